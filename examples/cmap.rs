@@ -1,4 +1,4 @@
-use chromatic::{ChromaticError, Colour, HsvAlphaMap};
+use chromatic::prelude::{ChromaticError, HsvAlphaMap};
 
 fn main() -> Result<(), ChromaticError> {
     let colours = [
@@ -11,7 +11,7 @@ fn main() -> Result<(), ChromaticError> {
     for i in 0..=100 {
         let pos = i as f32 / 100.0;
         let colour = cmap.sample(pos)?;
-        println!("{} {}", colour, colour.to_hex()?);
+        println!("{colour:#} {colour}");
     }
 
     println!("{}", cmap);
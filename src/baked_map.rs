@@ -0,0 +1,157 @@
+//! ## `BakedMap` Module
+//!
+//! This module provides the `BakedMap` struct, a precomputed lookup table of a `ColourMap` for fast sampling.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use num_traits::Float;
+
+use crate::{colour_map::ColourMap, error::Result, traits::Colour};
+
+/// A colour map pre-sampled at evenly spaced positions, for constant-time lookups in tight rendering loops.
+///
+/// Created with [`ColourMap::bake`]. Sampling never fails: positions are clamped to [0, 1] and `NaN` is treated as 0.
+///
+/// The table trades accuracy for speed. [`BakedMap::sample`] returns the nearest cell, so its position error is
+/// up to half a cell, `1 / (2 * (resolution - 1))`. [`BakedMap::sample_blended`] interpolates linearly between
+/// adjacent cells in the colour's own space, which is exact at the cells and matches the original map between
+/// them wherever the map is linear across the cell.
+#[derive(Debug, Clone)]
+pub struct BakedMap<C, T, const N: usize>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// The pre-sampled colours, evenly spaced over [0, 1].
+    colours: Vec<C>,
+    /// Phantom type for the colour space.
+    _phantom: PhantomData<T>,
+}
+
+impl<C, T, const N: usize> ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// Pre-sample the map into a lookup table for fast repeated sampling.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution` - Number of evenly spaced cells in the table, must not be zero
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resolution` is zero or if sampling fails.
+    pub fn bake(&self, resolution: usize) -> Result<BakedMap<C, T, N>> {
+        Ok(BakedMap {
+            colours: self.sample_n(resolution)?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<C, T, const N: usize> BakedMap<C, T, N>
+where
+    C: Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// Get the number of cells in the table.
+    #[must_use]
+    pub const fn resolution(&self) -> usize {
+        self.colours.len()
+    }
+
+    /// Get a reference to the pre-sampled colours.
+    #[must_use]
+    pub fn colours(&self) -> &[C] {
+        &self.colours
+    }
+
+    /// Sample the table at a position by returning the nearest cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, clamped to [0, 1]
+    #[must_use]
+    pub fn sample(&self, position: T) -> C {
+        let (index, t) = self.cell(position);
+        let half = T::from(0.5).unwrap_or_else(T::zero);
+        let index = if t < half {
+            index
+        } else {
+            (index + 1).min(self.colours.len() - 1)
+        };
+        self.colours[index].clone()
+    }
+
+    /// Sample the table at a position by interpolating linearly between the adjacent cells.
+    /// If interpolation fails, the nearer cell is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, clamped to [0, 1]
+    #[must_use]
+    pub fn sample_blended(&self, position: T) -> C {
+        let (index, t) = self.cell(position);
+        let Some(end) = self.colours.get(index + 1) else {
+            return self.colours[index].clone();
+        };
+
+        let start = &self.colours[index];
+        C::lerp(start, end, t).unwrap_or_else(|_| {
+            if t < T::from(0.5).unwrap_or_else(T::zero) {
+                start.clone()
+            } else {
+                end.clone()
+            }
+        })
+    }
+
+    /// Find the cell at or before a position, and the fractional distance to the next cell.
+    fn cell(&self, position: T) -> (usize, T) {
+        let last = self.colours.len() - 1;
+        if last == 0 {
+            return (0, T::zero());
+        }
+
+        let scaled_pos = position.max(T::zero()).min(T::one()) * T::from(last).unwrap_or_else(T::zero);
+        let index = scaled_pos.floor().to_usize().unwrap_or(0).min(last - 1);
+        (index, scaled_pos - T::from(index).unwrap_or_else(T::zero))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{colour_map::RgbMap, spaces::Rgb};
+
+    #[test]
+    fn baked_map_matches_direct_sampling() -> Result<()> {
+        let map = RgbMap::<f64>::new(&[Rgb::new(0.0, 0.0, 0.5)?, Rgb::new(0.9, 0.2, 0.1)?, Rgb::new(1.0, 1.0, 0.8)?])?;
+        let baked = map.bake(256)?;
+        assert_eq!(baked.resolution(), 256);
+
+        for step in 0..=1000 {
+            let position = f64::from(step) / 1000.0;
+            let expected = map.sample(position)?;
+            // Blending is exact except in the cell straddling the middle control point
+            for (lhs, rhs) in baked.sample_blended(position).components().iter().zip(expected.components()) {
+                assert!((lhs - rhs).abs() < 1e-2);
+            }
+            // Nearest-cell lookups are off by at most half a cell
+            for (lhs, rhs) in baked.sample(position).components().iter().zip(expected.components()) {
+                assert!((lhs - rhs).abs() < 1.0 / 255.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn baking_requires_a_resolution() -> Result<()> {
+        let map = RgbMap::<f64>::new(&[Rgb::new(0.0, 0.0, 0.0)?, Rgb::new(1.0, 1.0, 1.0)?])?;
+
+        assert!(map.bake(0).is_err());
+        assert!((map.bake(1)?.sample(0.7).red()).abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -2,32 +2,76 @@
 //!
 //! This module provides the `ColourMap` struct, which allows for interpolation between colours.
 
-use num_traits::Float;
-use std::{
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{
+    cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
     marker::PhantomData,
+    ops::{Index, IndexMut},
 };
+use num_traits::Float;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
+#[cfg(feature = "std")]
 use terminal_size::{Width, terminal_size};
 
 use crate::{
-    error::{ColourMapError, Result, safe_constant, validate_interpolation_factor},
-    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::Colour,
+    easing::Easing,
+    error::{
+        ColourMapError, ColourParsingError, Result, clamp_unit_component, component_to_u8, safe_constant, validate_gamma,
+        validate_interpolation_factor,
+    },
+    spaces::{
+        AdobeRgb, Cmyk, DisplayP3, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, Lchuv, Luv,
+        Oklab, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+    },
+    traits::{Colour, Convert},
 };
 
+/// Number of columns printed by `Display` when the terminal width is unknown.
+const DISPLAY_WIDTH: u16 = 60;
+
+/// Number of segments in the arc-length table used by `ColourMap::sample_perceptual_n`.
+const PERCEPTUAL_SEGMENTS: usize = 256;
+
+/// Number of golden-section iterations used to refine `ColourMap::locate`.
+const LOCATE_ITERATIONS: usize = 40;
+
 // Type aliases for easier usage
+/// Adobe RGB (1998) colour map.
+pub type AdobeRgbMap<T> = ColourMap<AdobeRgb<T>, T, 3>;
+/// Cyan, Magenta, Yellow, Key (CMYK) colour map.
+pub type CmykMap<T> = ColourMap<Cmyk<T>, T, 4>;
+/// Display P3 colour map.
+pub type DisplayP3Map<T> = ColourMap<DisplayP3<T>, T, 3>;
 /// Monochrome (Grey) colour map.
 pub type GreyMap<T> = ColourMap<Grey<T>, T, 1>;
+/// `HPLuv` colour map.
+pub type HpluvMap<T> = ColourMap<Hpluv<T>, T, 3>;
 /// Hue, Saturation, Lightness colour map.
+///
+/// Hue is interpolated along the shortest arc, so a map from 350° to 10° passes through 0° rather than 180°.
 pub type HslMap<T> = ColourMap<Hsl<T>, T, 3>;
+/// `HSLuv` colour map.
+pub type HsluvMap<T> = ColourMap<Hsluv<T>, T, 3>;
 /// Hue, Saturation, Value colour map.
+///
+/// Hue is interpolated along the shortest arc, so a map from 350° to 10° passes through 0° rather than 180°.
 pub type HsvMap<T> = ColourMap<Hsv<T>, T, 3>;
 /// CIE L*a*b* colour map.
 pub type LabMap<T> = ColourMap<Lab<T>, T, 3>;
-/// Red, Green, Blue colour map.
-pub type RgbMap<T> = ColourMap<Rgb<T>, T, 3>;
+/// `LCh(uv)` colour map.
+pub type LchuvMap<T> = ColourMap<Lchuv<T>, T, 3>;
+/// CIE L*u*v* colour map.
+pub type LuvMap<T> = ColourMap<Luv<T>, T, 3>;
+/// Oklab colour map.
+pub type OklabMap<T> = ColourMap<Oklab<T>, T, 3>;
 /// Linear RGB colour map.
+pub type RgbMap<T> = ColourMap<Rgb<T>, T, 3>;
+/// Standard RGB (gamma-encoded) colour map.
 pub type SrgbMap<T> = ColourMap<Srgb<T>, T, 3>;
+/// CIE xyY colour map.
+pub type XyYMap<T> = ColourMap<XyY<T>, T, 3>;
 /// CIE XYZ colour map.
 pub type XyzMap<T> = ColourMap<Xyz<T>, T, 3>;
 
@@ -40,13 +84,34 @@ pub type HslAlphaMap<T> = ColourMap<HslAlpha<T>, T, 4>;
 pub type HsvAlphaMap<T> = ColourMap<HsvAlpha<T>, T, 4>;
 /// CIE L*a*b* colour map with alpha channel.
 pub type LabAlphaMap<T> = ColourMap<LabAlpha<T>, T, 4>;
-/// Red, Green, Blue colour map with alpha channel.
-pub type RgbAlphaMap<T> = ColourMap<RgbAlpha<T>, T, 4>;
 /// Linear RGB colour map with alpha channel.
+pub type RgbAlphaMap<T> = ColourMap<RgbAlpha<T>, T, 4>;
+/// Standard RGB (gamma-encoded) colour map with alpha channel.
 pub type SrgbAlphaMap<T> = ColourMap<SrgbAlpha<T>, T, 4>;
 /// CIE XYZ colour map with alpha channel.
 pub type XyzAlphaMap<T> = ColourMap<XyzAlpha<T>, T, 4>;
 
+/// Construct a `ColourMap` from hex strings with optional positions, returning a `Result`.
+///
+/// Each stop is a hex string literal, optionally followed by `@ position`.
+/// Missing positions are filled in as described for `ColourMap::from_hex_stops`.
+///
+/// ```rust,ignore
+/// let cmap = colour_map![RgbMap<f64>; "#000000", "#FF0000" @ 0.3, "#FFFFFF"]?;
+/// ```
+#[macro_export]
+macro_rules! colour_map {
+    (@position) => {
+        None
+    };
+    (@position $position:expr) => {
+        Some($position)
+    };
+    ($map:ty; $($hex:literal $(@ $position:expr)?),+ $(,)?) => {
+        <$map>::from_hex_stops(&[$(($hex, $crate::colour_map!(@position $($position)?))),+])
+    };
+}
+
 /// A map of colours at specific positions, with interpolation between them.
 #[derive(Debug, Clone)]
 pub struct ColourMap<C, T, const N: usize>
@@ -56,6 +121,10 @@ where
 {
     /// The colours in the map.
     colours: Vec<C>,
+    /// Explicit positions of the colours in [0, 1], or `None` if they are uniformly spaced.
+    positions: Option<Vec<T>>,
+    /// Number of segments between consecutive colours, cached as `T` for `ColourMap::sample_unchecked`.
+    segments: T,
     /// Phantom type for the colour space.
     _phantom: PhantomData<T>,
 }
@@ -65,6 +134,17 @@ where
     C: Clone + Colour<T, N>,
     T: Float + Send + Sync,
 {
+    /// Assemble a map from already validated parts, caching the segment count.
+    fn from_parts(colours: Vec<C>, positions: Option<Vec<T>>) -> Self {
+        let segments = T::from(colours.len().saturating_sub(1)).unwrap_or_else(T::zero);
+        Self {
+            colours,
+            positions,
+            segments,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Create a new colour map with uniformly spaced positions.
     ///
     /// # Arguments
@@ -79,10 +159,7 @@ where
             return Err(ColourMapError::EmptyColourMap.into());
         }
 
-        Ok(Self {
-            colours: colours.to_vec(),
-            _phantom: PhantomData,
-        })
+        Ok(Self::from_parts(colours.to_vec(), None))
     }
 
     /// Construct a `ColourMap` from a vector of Hex strings.
@@ -103,10 +180,42 @@ where
 
         let colours: Result<Vec<C>> = hex_colours.iter().map(|hex| C::from_hex(hex)).collect();
 
-        Ok(Self {
-            colours: colours?,
-            _phantom: PhantomData,
-        })
+        Ok(Self::from_parts(colours?, None))
+    }
+
+    /// Construct a `ColourMap` by evaluating a function at evenly spaced positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_colours` - Number of control points to generate, must not be zero
+    /// * `f` - Function mapping a position in [0, 1] to a colour
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `num_colours` is zero.
+    /// - The function returns an error for any position.
+    pub fn from_fn<F>(num_colours: usize, f: F) -> Result<Self>
+    where
+        F: Fn(T) -> Result<C>,
+    {
+        if num_colours == 0 {
+            return Err(ColourMapError::EmptyColourMap.into());
+        }
+
+        if num_colours == 1 {
+            return Self::new(&[f(T::zero())?]);
+        }
+
+        let mut colours = Vec::with_capacity(num_colours);
+        let denominator = safe_constant::<usize, T>(num_colours - 1)?;
+
+        for i in 0..num_colours {
+            let position = safe_constant::<usize, T>(i)? / denominator;
+            colours.push(f(position)?);
+        }
+
+        Ok(Self::from_parts(colours, None))
     }
 
     /// Sample the colour map at a given position.
@@ -123,39 +232,267 @@ where
         // Validate sampling position using our standard helper
         validate_interpolation_factor(position)?;
 
-        // Single colour case
-        if self.colours.len() == 1 {
+        // Empty and single colour cases
+        match self.colours.as_slice() {
+            [] => return Err(ColourMapError::EmptyMapSampling.into()),
+            [colour] => return Ok(colour.clone()),
+            _ => {}
+        }
+
+        // Edge cases - positions at or beyond the first and last stops take the end colours exactly
+        let last = self.colours.len() - 1;
+        if position <= self.stop_position(0)? {
             return Ok(self.colours[0].clone());
         }
+        if position >= self.stop_position(last)? {
+            return Ok(self.colours[last].clone());
+        }
+
+        // Perform the interpolation
+        let (segment_idx, t) = self.segment(position)?;
+        C::lerp(&self.colours[segment_idx], &self.colours[segment_idx + 1], t)
+    }
+
+    /// Sample the colour map after remapping the position through an easing curve.
+    ///
+    /// [`Easing::Linear`] gives exactly the same result as [`ColourMap::sample`].
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, must be in range [0, 1]
+    /// * `easing` - The curve applied to the position before sampling
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is outside the range [0, 1], or if sampling fails.
+    pub fn sample_eased(&self, position: T, easing: Easing) -> Result<C> {
+        self.sample(easing.apply(position)?)
+    }
+
+    /// Sample the colour map at a given position using Catmull-Rom spline interpolation.
+    ///
+    /// Each segment is interpolated through the four surrounding control points, with the first and last
+    /// colours duplicated to supply the missing neighbours at the ends of the map. This removes the creases
+    /// that linear interpolation leaves at each control point.
+    /// The spline is evaluated on the colour's `components()`, so hues are not unwrapped, and any overshoot
+    /// outside a component's valid range is clamped (see [`Colour::from_components_clamped`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sampled position is outside the range [0, 1],
+    /// or if numeric conversion fails during interpolation calculations.
+    pub fn sample_cubic(&self, position: T) -> Result<C> {
+        validate_interpolation_factor(position)?;
+
+        // Empty and single colour cases
+        match self.colours.as_slice() {
+            [] => return Err(ColourMapError::EmptyMapSampling.into()),
+            [colour] => return Ok(colour.clone()),
+            _ => {}
+        }
 
-        // Edge cases - use exact comparisons since we've already validated the range
-        if position <= T::zero() {
+        // Edge cases
+        let last = self.colours.len() - 1;
+        if position <= self.stop_position(0)? {
             return Ok(self.colours[0].clone());
         }
-        if position >= T::one() {
-            return Ok(self.colours[self.colours.len() - 1].clone());
+        if position >= self.stop_position(last)? {
+            return Ok(self.colours[last].clone());
+        }
+
+        let (segment_idx, t) = self.segment(position)?;
+        let p0 = self.colours[segment_idx.saturating_sub(1)].components();
+        let p1 = self.colours[segment_idx].components();
+        let p2 = self.colours[segment_idx + 1].components();
+        let p3 = self.colours[(segment_idx + 2).min(last)].components();
+
+        let half = safe_constant::<f64, T>(0.5)?;
+        let two = safe_constant::<f64, T>(2.0)?;
+        let three = safe_constant::<f64, T>(3.0)?;
+        let four = safe_constant::<f64, T>(4.0)?;
+        let five = safe_constant::<f64, T>(5.0)?;
+        let (t2, t3) = (t * t, t * t * t);
+
+        C::from_components_clamped(core::array::from_fn(|i| {
+            half * (two * p1[i]
+                + (p2[i] - p0[i]) * t
+                + (two * p0[i] - five * p1[i] + four * p2[i] - p3[i]) * t2
+                + (three * (p1[i] - p2[i]) + p3[i] - p0[i]) * t3)
+        }))
+    }
+
+    /// Sample the colour map without validating the position, for use in tight rendering loops.
+    ///
+    /// The caller is responsible for passing a position in the range [0, 1]; no error is reported otherwise.
+    /// For such positions the result matches [`ColourMap::sample`] up to floating-point rounding.
+    /// Out-of-range positions are clamped to the nearest endpoint and `NaN` is treated as 0, so this never panics.
+    /// If interpolation fails, the nearer of the two surrounding control points is returned.
+    /// The segment count is cached on the map, so uniformly spaced maps find their segment with one multiply
+    /// and a floor, without converting any constants.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, assumed to be in range [0, 1]
+    #[must_use]
+    pub fn sample_unchecked(&self, position: T) -> C {
+        let last = self.colours.len() - 1;
+        if last == 0 {
+            return self.colours[0].clone();
+        }
+
+        let position = position.max(T::zero()).min(T::one());
+        let (segment_idx, t) = self.positions.as_ref().map_or_else(
+            || {
+                let scaled_pos = position * self.segments;
+                let floor = scaled_pos.floor();
+                match floor.to_usize() {
+                    Some(segment_idx) if segment_idx < last => (segment_idx, scaled_pos - floor),
+                    // Only position 1 reaches the end of the last segment
+                    _ => (last - 1, T::one()),
+                }
+            },
+            |positions| {
+                let segment_idx = positions
+                    .partition_point(|&stop| stop <= position)
+                    .saturating_sub(1)
+                    .min(last - 1);
+                let (start, end) = (positions[segment_idx], positions[segment_idx + 1]);
+                (segment_idx, ((position - start) / (end - start)).max(T::zero()).min(T::one()))
+            },
+        );
+
+        let (start, end) = (&self.colours[segment_idx], &self.colours[segment_idx + 1]);
+        C::lerp(start, end, t).unwrap_or_else(|_| if t + t < T::one() { start.clone() } else { end.clone() })
+    }
+
+    /// Sample the colour map, linearly extrapolating beyond the endpoints instead of rejecting them.
+    ///
+    /// Positions below 0 or above 1 continue the slope of the first or last segment in the colour's
+    /// component space, and the result is clamped to each component's valid range
+    /// (see [`Colour::from_components_clamped`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, must be finite
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is not finite, or if sampling fails.
+    pub fn sample_extrapolated(&self, position: T) -> Result<C> {
+        if !position.is_finite() {
+            return Err(ColourMapError::InvalidSamplingPosition {
+                position: position.to_f64().unwrap_or(f64::NAN),
+            }
+            .into());
+        }
+
+        if (T::zero()..=T::one()).contains(&position) {
+            return self.sample(position);
+        }
+        if self.colours.len() == 1 {
+            return Ok(self.colours[0].clone());
         }
 
-        // Calculate which segment we're in
-        let segments = safe_constant::<usize, T>(self.colours.len() - 1)?;
-        let scaled_pos = position * segments;
+        // Parameterise the end segment so that t=0 and t=1 fall on its control points,
+        // continuing from the end colour held between the outermost stop and the boundary
+        let last = self.colours.len() - 1;
+        let (start, end, t) = if position < T::zero() {
+            let width = self.stop_position(1)? - self.stop_position(0)?;
+            (&self.colours[0], &self.colours[1], position / width)
+        } else {
+            let width = self.stop_position(last)? - self.stop_position(last - 1)?;
+            (
+                &self.colours[last - 1],
+                &self.colours[last],
+                (position - T::one()) / width + T::one(),
+            )
+        };
 
-        // Get segment index, ensuring it's within bounds
-        let segment_idx = scaled_pos
-            .floor()
-            .to_usize()
-            .ok_or_else(|| ColourMapError::InvalidSamplingPosition {
+        let (start, end) = (start.components(), end.components());
+        C::from_components_clamped(core::array::from_fn(|i| start[i] + (end[i] - start[i]) * t))
+    }
+
+    /// Sample the colour map cyclically, wrapping positions into [0, 1) using their fractional part.
+    ///
+    /// Negative positions wrap too, so -0.25 samples at 0.75 and 1.0 samples the first colour.
+    /// The last and first colours are always adjacent, so there is no seam at 0:
+    /// a uniform map of N colours places them at i / N, so the final segment interpolates from the last colour
+    /// back to the first, and a positioned map interpolates across the gap after its last stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, must be finite
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is not finite, or if sampling fails.
+    pub fn sample_cyclic(&self, position: T) -> Result<C> {
+        if !position.is_finite() {
+            return Err(ColourMapError::InvalidSamplingPosition {
                 position: position.to_f64().unwrap_or(f64::NAN),
-            })?
-            .min(self.colours.len() - 2);
+            }
+            .into());
+        }
+
+        // Rounding can leave a tiny negative position one ulp short of 1 after wrapping
+        let wrapped = position - position.floor();
+        let wrapped = if wrapped >= T::one() { T::zero() } else { wrapped };
+        if self.colours.len() < 2 {
+            return self.sample(wrapped);
+        }
 
-        // Calculate interpolation parameter within the segment
-        let segment_start = safe_constant::<usize, T>(segment_idx)? / segments;
-        let segment_width = T::one() / segments;
-        let t = (position - segment_start) / segment_width;
+        let last = self.colours.len() - 1;
+        if self.positions.is_none() {
+            // Spread the colours over one segment each, the last of which runs back to the first colour
+            let scaled = wrapped * safe_constant(self.colours.len())?;
+            let idx = scaled.floor().to_usize().unwrap_or(last).min(last);
+            let t = clamp_unit_component(scaled - safe_constant(idx)?);
+            return C::lerp(&self.colours[idx], &self.colours[(idx + 1) % self.colours.len()], t);
+        }
 
-        // Perform the interpolation
-        C::lerp(&self.colours[segment_idx], &self.colours[segment_idx + 1], t)
+        let (first_stop, last_stop) = (self.stop_position(0)?, self.stop_position(last)?);
+        if (first_stop..=last_stop).contains(&wrapped) {
+            return self.sample(wrapped);
+        }
+
+        // Interpolate across the gap from the last stop, through 1 ≡ 0, to the first stop
+        let gap = first_stop + T::one() - last_stop;
+        let offset = if wrapped > last_stop {
+            wrapped - last_stop
+        } else {
+            wrapped + T::one() - last_stop
+        };
+        C::lerp(&self.colours[last], &self.colours[0], clamp_unit_component(offset / gap))
+    }
+
+    /// Sample the colour map with mirrored repetition, folding positions with a triangle wave of period 2.
+    ///
+    /// The gradient runs forwards over [0, 1], backwards over [1, 2], and so on,
+    /// so 1.2 samples at 0.8, 2.0 samples at 0.0, and negative positions are reflected about 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to sample at, must be finite
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position is not finite, or if sampling fails.
+    pub fn sample_mirror(&self, position: T) -> Result<C> {
+        if !position.is_finite() {
+            return Err(ColourMapError::InvalidSamplingPosition {
+                position: position.to_f64().unwrap_or(f64::NAN),
+            }
+            .into());
+        }
+
+        let two = safe_constant(2.0)?;
+        let folded = position.abs() % two;
+        let folded = if folded > T::one() { two - folded } else { folded };
+        self.sample(clamp_unit_component(folded))
     }
 
     /// Create a colour map from colours with explicit positions.
@@ -170,6 +507,7 @@ where
     /// - The input is empty
     /// - Any position is outside [0, 1]
     /// - Positions are not in ascending order
+    /// - Two consecutive positions are equal
     pub fn from_positions(colours_and_positions: &[(C, T)]) -> Result<Self> {
         if colours_and_positions.is_empty() {
             return Err(ColourMapError::EmptyColourMap.into());
@@ -181,7 +519,15 @@ where
 
             if i > 0 {
                 let prev_position = colours_and_positions[i - 1].1;
-                if *position <= prev_position {
+                if *position == prev_position {
+                    return Err(ColourMapError::DegenerateSpacing {
+                        position: position.to_f64().unwrap_or(f64::NAN),
+                        idx1: i - 1,
+                        idx2: i,
+                    }
+                    .into());
+                }
+                if *position < prev_position {
                     return Err(ColourMapError::NonAscendingPositions {
                         pos1: prev_position.to_f64().unwrap_or(f64::NAN),
                         idx1: i - 1,
@@ -193,13 +539,97 @@ where
             }
         }
 
-        // For now, we'll store just the colours and use uniform spacing
-        // A future enhancement could store the positions as well
-        let colours: Vec<C> = colours_and_positions.iter().map(|(c, _)| c.clone()).collect();
-        Ok(Self {
-            colours,
-            _phantom: PhantomData,
-        })
+        let (colours, positions) = colours_and_positions.iter().cloned().unzip();
+        Ok(Self::from_parts(colours, Some(positions)))
+    }
+
+    /// Construct a `ColourMap` from hex colours with optional positions.
+    ///
+    /// Missing positions are filled in as for CSS gradients: the first and last stops default to 0 and 1,
+    /// and any other stops without a position are spaced evenly between their positioned neighbours.
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - A slice of (hex, position) tuples, where positions must be in [0, 1] and ascending
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The input is empty.
+    /// - Any hex string is invalid.
+    /// - Any position is outside [0, 1] or positions are not in ascending order.
+    pub fn from_hex_stops(stops: &[(&str, Option<T>)]) -> Result<Self> {
+        if stops.is_empty() {
+            return Err(ColourMapError::EmptyColourMap.into());
+        }
+
+        let mut positions: Vec<Option<T>> = stops.iter().map(|&(_, position)| position).collect();
+        let last = positions.len() - 1;
+        if last > 0 {
+            positions[0] = positions[0].or_else(|| Some(T::zero()));
+            positions[last] = positions[last].or_else(|| Some(T::one()));
+        }
+
+        // Spread each run of missing positions evenly between the positioned stops either side
+        let mut start = 0;
+        for end in 1..positions.len() {
+            if let (Some(from), Some(to)) = (positions[start], positions[end]) {
+                let gaps = safe_constant::<usize, T>(end - start)?;
+                for (offset, position) in positions[start + 1..end].iter_mut().enumerate() {
+                    *position = Some(from + (to - from) * safe_constant::<usize, T>(offset + 1)? / gaps);
+                }
+                start = end;
+            }
+        }
+
+        let colours_and_positions = stops
+            .iter()
+            .zip(positions)
+            .map(|(&(hex, _), position)| Ok((C::from_hex(hex)?, position.unwrap_or_else(T::zero))))
+            .collect::<Result<Vec<(C, T)>>>()?;
+        Self::from_positions(&colours_and_positions)
+    }
+
+    /// Construct a `ColourMap` from a comma-separated list of stops.
+    ///
+    /// Each stop is either `position:hex` (e.g. `0.0:#000000, 0.5:#FF0000, 1.0:#FFFFFF`)
+    /// or a bare hex colour, whose position is filled in as described for [`ColourMap::from_hex_stops`].
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - The stops string to parse
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The string contains no stops.
+    /// - Any stop is empty, has an unparsable position, or an invalid hex colour.
+    /// - Any position is outside [0, 1] or positions are not in ascending order.
+    pub fn from_stops_str(stops: &str) -> Result<Self> {
+        let stops: Vec<&str> = stops.split(',').map(str::trim).collect();
+        if stops.iter().all(|stop| stop.is_empty()) {
+            return Err(ColourMapError::EmptyColourMap.into());
+        }
+
+        let mut hex_stops = Vec::with_capacity(stops.len());
+        for stop in &stops {
+            if stop.is_empty() {
+                return Err(ColourParsingError::InvalidFormat(format!("Empty stop in '{}'", stops.join(", "))).into());
+            }
+
+            match stop.split_once(':') {
+                Some((position, hex)) => {
+                    let position = position
+                        .trim()
+                        .parse::<f64>()
+                        .map_err(|_| ColourParsingError::InvalidFormat(format!("Invalid stop position in '{stop}'")))?;
+                    hex_stops.push((hex.trim(), Some(safe_constant::<f64, T>(position)?)));
+                }
+                None => hex_stops.push((*stop, None)),
+            }
+        }
+
+        Self::from_hex_stops(&hex_stops)
     }
 
     /// Sample the colour map at a given position with custom interpolation.
@@ -218,36 +648,235 @@ where
     {
         validate_interpolation_factor(position)?;
 
-        // Single colour case
-        if self.colours.len() == 1 {
-            return Ok(self.colours[0].clone());
+        // Empty and single colour cases
+        match self.colours.as_slice() {
+            [] => return Err(ColourMapError::EmptyMapSampling.into()),
+            [colour] => return Ok(colour.clone()),
+            _ => {}
         }
 
         // Edge cases
-        if position <= T::zero() {
+        let last = self.colours.len() - 1;
+        if position <= self.stop_position(0)? {
             return Ok(self.colours[0].clone());
         }
-        if position >= T::one() {
-            return Ok(self.colours[self.colours.len() - 1].clone());
+        if position >= self.stop_position(last)? {
+            return Ok(self.colours[last].clone());
         }
 
-        // Calculate segment and interpolate using custom function
-        let segments = safe_constant::<usize, T>(self.colours.len() - 1)?;
-        let scaled_pos = position * segments;
+        // Interpolate using the custom function
+        let (segment_idx, t) = self.segment(position)?;
+        interpolation_fn(&self.colours[segment_idx], &self.colours[segment_idx + 1], t)
+    }
 
-        let segment_idx = scaled_pos
-            .floor()
-            .to_usize()
-            .ok_or_else(|| ColourMapError::InvalidSamplingPosition {
-                position: position.to_f64().unwrap_or(f64::NAN),
-            })?
-            .min(self.colours.len() - 2);
+    /// Get the position of the control point at `index`, whether stored explicitly or uniformly spaced.
+    ///
+    /// The index must be less than the number of colours.
+    fn stop_position(&self, index: usize) -> Result<T> {
+        match &self.positions {
+            Some(positions) => Ok(positions[index]),
+            None if self.colours.len() > 1 => {
+                Ok(safe_constant::<usize, T>(index)? / safe_constant::<usize, T>(self.colours.len() - 1)?)
+            }
+            None => Ok(T::zero()),
+        }
+    }
 
-        let segment_start = safe_constant::<usize, T>(segment_idx)? / segments;
-        let segment_width = T::one() / segments;
-        let t = (position - segment_start) / segment_width;
+    /// Find the segment containing a position strictly between the first and last stops,
+    /// and the interpolation parameter within it.
+    ///
+    /// The map must have at least two colours. The parameter is clamped to [0, 1] to absorb rounding error.
+    fn segment(&self, position: T) -> Result<(usize, T)> {
+        let last = self.colours.len().checked_sub(1).ok_or(ColourMapError::EmptyMapSampling)?;
 
-        interpolation_fn(&self.colours[segment_idx], &self.colours[segment_idx + 1], t)
+        let (segment_idx, segment_start, segment_width) = if let Some(positions) = &self.positions {
+            // Binary search for the last stop at or before the position
+            let segment_idx = positions
+                .partition_point(|&stop| stop <= position)
+                .saturating_sub(1)
+                .min(last.saturating_sub(1));
+            let segment_start = positions[segment_idx];
+            (segment_idx, segment_start, positions[segment_idx + 1] - segment_start)
+        } else {
+            let segments = safe_constant::<usize, T>(last)?;
+
+            // Get segment index, ensuring it's within bounds
+            let segment_idx = (position * segments)
+                .floor()
+                .to_usize()
+                .ok_or_else(|| ColourMapError::InvalidSamplingPosition {
+                    position: position.to_f64().unwrap_or(f64::NAN),
+                })?
+                .min(last.saturating_sub(1));
+            (
+                segment_idx,
+                safe_constant::<usize, T>(segment_idx)? / segments,
+                T::one() / segments,
+            )
+        };
+
+        // Guard against dividing by a segment with no width
+        if !(segment_width > T::zero() && segment_width.is_finite()) {
+            return Err(ColourMapError::DegenerateSpacing {
+                position: segment_start.to_f64().unwrap_or(f64::NAN),
+                idx1: segment_idx,
+                idx2: segment_idx + 1,
+            }
+            .into());
+        }
+
+        let t = ((position - segment_start) / segment_width).max(T::zero()).min(T::one());
+        Ok((segment_idx, t))
+    }
+
+    /// Extract the section of the map between two positions as a new uniformly spaced map.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The position in this map that becomes position 0, must be in range [0, 1]
+    /// * `end` - The position in this map that becomes position 1, must be in range [0, 1] and greater than `start`
+    /// * `num_colours` - Number of control points in the new map, must not be zero
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `start` or `end` is outside [0, 1], or `start` is not less than `end`.
+    /// - `num_colours` is zero.
+    /// - Sampling fails.
+    pub fn subrange(&self, start: T, end: T, num_colours: usize) -> Result<Self> {
+        validate_interpolation_factor(start)?;
+        validate_interpolation_factor(end)?;
+        if start >= end {
+            return Err(ColourMapError::NonAscendingPositions {
+                pos1: start.to_f64().unwrap_or(f64::NAN),
+                idx1: 0,
+                pos2: end.to_f64().unwrap_or(f64::NAN),
+                idx2: 1,
+            }
+            .into());
+        }
+
+        Self::from_fn(num_colours, |t| self.sample((start + (end - start) * t).min(end)))
+    }
+
+    /// Create a copy of the map with the order of its colours flipped,
+    /// so sampling the copy at `p` matches sampling this map at `1 - p`.
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        let mut reversed = self.clone();
+        reversed.reverse();
+        reversed
+    }
+
+    /// Flip the order of the colours in the map in place.
+    /// Explicit positions are mirrored, so each stop `p` moves to `1 - p`.
+    pub fn reverse(&mut self) {
+        self.colours.reverse();
+        if let Some(positions) = &mut self.positions {
+            positions.reverse();
+            for position in positions.iter_mut() {
+                *position = T::one() - *position;
+            }
+        }
+    }
+
+    /// Join another map onto the end of this one.
+    ///
+    /// If both maps are uniformly spaced the result is uniformly spaced over the combined count.
+    /// Otherwise each map keeps its relative spacing, compressed into the span its control points
+    /// would occupy in a uniform map of the combined count.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The map whose colours follow this map's colours
+    #[must_use]
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut joined = self.clone();
+        joined.append(&other.colours, other.positions.as_deref());
+        joined
+    }
+
+    /// Append a colour to the end of the map.
+    ///
+    /// For maps with explicit positions the existing stops are compressed, as for [`ColourMap::concat`],
+    /// and the new colour is placed at position 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `colour` - The colour to add after the current last colour
+    pub fn push(&mut self, colour: C) {
+        self.append(&[colour], None);
+    }
+
+    /// Append colours with optional explicit positions, rescaling positions if either side has them.
+    fn append(&mut self, colours: &[C], positions: Option<&[T]>) {
+        let (len, other_len) = (self.colours.len(), colours.len());
+        self.colours.extend_from_slice(colours);
+        self.segments = T::from(self.colours.len() - 1).unwrap_or_else(T::zero);
+        if self.positions.is_none() && positions.is_none() {
+            return;
+        }
+
+        let as_t = |value: usize| T::from(value).unwrap_or_else(T::zero);
+        let uniform = |count: usize| -> Vec<T> { (0..count).map(|i| as_t(i) / as_t(count.saturating_sub(1).max(1))).collect() };
+
+        // Each side spans the stops it would cover in a uniform map of the combined count
+        let denominator = as_t((len + other_len).saturating_sub(1).max(1));
+        let (end, start) = (as_t(len.saturating_sub(1)) / denominator, as_t(len) / denominator);
+        let own = self.positions.take().unwrap_or_else(|| uniform(len));
+        let other = positions.map_or_else(|| uniform(other_len), <[T]>::to_vec);
+
+        self.positions = Some(
+            own.into_iter()
+                .map(|position| position * end)
+                .chain(other.into_iter().map(|position| start + position * (T::one() - start)))
+                .collect(),
+        );
+    }
+
+    /// Interpolate between this map and another, control point by control point.
+    /// If the maps have different lengths or positions, both are resampled at uniformly spaced positions
+    /// to match the longer one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The map to interpolate towards (at t=1)
+    /// * `t` - The interpolation factor, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interpolation factor is outside [0, 1], or if resampling or interpolation fails.
+    pub fn lerp_map(&self, other: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        // Maps sharing their stops can be interpolated directly, keeping those stops
+        if self.len() == other.len() && self.positions == other.positions {
+            let colours = self
+                .colours
+                .iter()
+                .zip(&other.colours)
+                .map(|(lhs, rhs)| C::lerp(lhs, rhs, t))
+                .collect::<Result<Vec<_>>>()?;
+
+            return Ok(Self::from_parts(colours, self.positions.clone()));
+        }
+
+        let num_colours = self.len().max(other.len());
+        let resample = |map: &Self| {
+            if map.positions.is_none() && map.len() == num_colours {
+                Ok(map.colours.clone())
+            } else {
+                map.sample_n(num_colours)
+            }
+        };
+
+        let colours = resample(self)?
+            .iter()
+            .zip(&resample(other)?)
+            .map(|(lhs, rhs)| C::lerp(lhs, rhs, t))
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::new(&colours)
     }
 
     /// Get the number of control points in the `ColourMap`.
@@ -268,8 +897,14 @@ where
         &self.colours
     }
 
+    /// Get the explicit positions of the colours, or `None` if they are uniformly spaced.
+    #[must_use]
+    pub fn positions(&self) -> Option<&[T]> {
+        self.positions.as_deref()
+    }
+
     /// Get an iterator over the colours in the map.
-    pub fn iter(&self) -> std::slice::Iter<'_, C> {
+    pub fn iter(&self) -> core::slice::Iter<'_, C> {
         self.colours.iter()
     }
 
@@ -301,57 +936,1582 @@ where
 
         Ok(samples)
     }
-}
 
-impl<C, T, const N: usize> Display for ColourMap<C, T, N>
-where
-    C: Display + Clone + Colour<T, N>,
-    T: Float + Send + Sync,
-{
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
-        let width = terminal_size().map_or(60, |(Width(w), _)| w).min(200); // Cap at reasonable width
+    /// Generate a vector of sampled colours across the entire map, sampling in parallel.
+    ///
+    /// The output is identical to [`ColourMap::sample_n`], and if sampling fails the error for the lowest
+    /// position is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - Number of samples to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sampling fails or if `num_samples` is 0.
+    #[cfg(feature = "rayon")]
+    pub fn par_sample_n(&self, num_samples: usize) -> Result<Vec<C>>
+    where
+        C: Send + Sync,
+    {
+        if num_samples == 0 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
+        }
 
-        let denom = width.saturating_sub(1).max(1);
+        if num_samples == 1 {
+            return Ok(vec![self.sample(T::zero())?]);
+        }
 
-        for i in 0..width {
-            // Use our safe conversion helpers
-            let position = match (safe_constant::<u16, T>(i), safe_constant::<u16, T>(denom)) {
-                (Ok(i_t), Ok(denom_t)) => i_t / denom_t,
-                _ => return Err(std::fmt::Error),
-            };
+        let denominator = safe_constant::<usize, T>(num_samples - 1)?;
+        let samples: Vec<Result<C>> = (0..num_samples)
+            .into_par_iter()
+            .map(|i| self.sample(safe_constant::<usize, T>(i)? / denominator))
+            .collect();
 
-            match self.sample(position) {
-                Ok(colour) => write!(fmt, "{colour}")?,
-                Err(_) => return Err(std::fmt::Error),
-            }
+        samples.into_iter().collect()
+    }
+
+    /// Generate a vector of evenly spaced positions paired with their sampled colours.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - Number of samples to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sampling fails or if `num_samples` is 0.
+    pub fn sampled_pairs(&self, num_samples: usize) -> Result<Vec<(T, C)>> {
+        if num_samples == 0 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
         }
-        Ok(())
+
+        if num_samples == 1 {
+            return Ok(vec![(T::zero(), self.sample(T::zero())?)]);
+        }
+
+        let mut pairs = Vec::with_capacity(num_samples);
+        let denominator = safe_constant::<usize, T>(num_samples - 1)?;
+
+        for i in 0..num_samples {
+            let position = safe_constant::<usize, T>(i)? / denominator;
+            pairs.push((position, self.sample(position)?));
+        }
+
+        Ok(pairs)
     }
 }
 
-// Implement IntoIterator for easier usage
-impl<C, T, const N: usize> IntoIterator for ColourMap<C, T, N>
+impl<C, T, const N: usize> ColourMap<C, T, N>
 where
-    C: Colour<T, N>,
+    C: Clone + Colour<T, N> + Convert<T>,
     T: Float + Send + Sync,
 {
-    type Item = C;
-    type IntoIter = std::vec::IntoIter<C>;
+    /// Create the photographic negative of the map.
+    ///
+    /// Each control point is inverted per linear RGB channel (`1 - component`).
+    /// The alpha component is preserved for colour spaces with transparency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from RGB fails for any control point.
+    pub fn negate(&self) -> Result<Self> {
+        self.map_rgb_alpha(RgbAlpha::negate)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.colours.into_iter()
+    /// Create a foreground map that is readable over this map used as a background.
+    ///
+    /// Each control point is replaced with opaque black or white, whichever has the higher
+    /// WCAG contrast ratio against the relative luminance of that control point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to XYZ or from RGB fails for any control point.
+    pub fn contrasting_foreground(&self) -> Result<Self> {
+        let offset = safe_constant(0.05)?;
+
+        self.map_rgb_alpha(|colour| {
+            let luminance = colour.to_xyz()?.y();
+
+            // WCAG contrast ratios of white and black text against the background
+            let white_contrast = (T::one() + offset) / (luminance + offset);
+            let black_contrast = (luminance + offset) / offset;
+
+            let value = if white_contrast > black_contrast {
+                T::one()
+            } else {
+                T::zero()
+            };
+            RgbAlpha::new(value, value, value, T::one())
+        })
     }
-}
 
-impl<'a, C, T, const N: usize> IntoIterator for &'a ColourMap<C, T, N>
-where
-    C: Colour<T, N>,
-    T: Float + Send + Sync,
-{
-    type Item = &'a C;
-    type IntoIter = std::slice::Iter<'a, C>;
+    /// Find the positions at which the map's relative luminance crosses a target value.
+    ///
+    /// The map is scanned at `resolution` evenly spaced samples, and each crossing position
+    /// is linearly interpolated between the two samples either side of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The relative luminance (XYZ `Y`) to search for
+    /// * `resolution` - Number of samples to scan, must be at least 2
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resolution` is less than 2, or if sampling or XYZ conversion fails.
+    pub fn positions_at_luminance(&self, target: T, resolution: usize) -> Result<Vec<T>> {
+        if resolution < 2 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.colours.iter()
+        let samples = self
+            .sampled_pairs(resolution)?
+            .into_iter()
+            .map(|(position, colour)| Ok((position, colour.to_xyz()?.y() - target)))
+            .collect::<Result<Vec<(T, T)>>>()?;
+
+        let mut positions = Vec::new();
+        for (i, window) in samples.windows(2).enumerate() {
+            let ((pos0, diff0), (pos1, diff1)) = (window[0], window[1]);
+
+            if diff0 == T::zero() {
+                positions.push(pos0);
+            } else if (diff0 < T::zero()) != (diff1 < T::zero()) && diff1 != T::zero() {
+                positions.push(pos0 + (pos1 - pos0) * diff0 / (diff0 - diff1));
+            }
+
+            // Include an exact hit on the final sample
+            if i == samples.len() - 2 && diff1 == T::zero() {
+                positions.push(pos1);
+            }
+        }
+
+        Ok(positions)
+    }
+
+    /// Find the position in the map whose sample is perceptually closest to a colour.
+    ///
+    /// The map is first scanned at `resolution` evenly spaced positions, then the closest match is refined
+    /// with a golden-section search over its neighbouring interval, minimising the CIEDE2000 difference.
+    ///
+    /// # Arguments
+    ///
+    /// * `colour` - The colour to locate
+    /// * `resolution` - Number of evenly spaced samples in the initial scan, must be at least 2
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resolution` is less than 2, or if sampling or conversion to Lab fails.
+    pub fn locate(&self, colour: &C, resolution: usize) -> Result<T> {
+        if resolution < 2 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
+        }
+
+        let target = colour.to_lab()?;
+        let distance = |position: T| self.sample(position)?.to_lab()?.delta_e2000(&target);
+
+        // Coarse scan for the closest sample
+        let denominator = safe_constant::<usize, T>(resolution - 1)?;
+        let mut best = (0, distance(T::zero())?);
+        for i in 1..resolution {
+            let delta_e = distance(safe_constant::<usize, T>(i)? / denominator)?;
+            if delta_e < best.1 {
+                best = (i, delta_e);
+            }
+        }
+
+        // Refine within the neighbouring samples using a golden-section search
+        let mut lower = safe_constant::<usize, T>(best.0.saturating_sub(1))? / denominator;
+        let mut upper = safe_constant::<usize, T>((best.0 + 1).min(resolution - 1))? / denominator;
+        let inv_phi = (safe_constant::<f64, T>(5.0)?.sqrt() - T::one()) / safe_constant(2.0)?;
+
+        let mut left = upper - (upper - lower) * inv_phi;
+        let mut right = lower + (upper - lower) * inv_phi;
+        let (mut left_distance, mut right_distance) = (distance(left)?, distance(right)?);
+        for _ in 0..LOCATE_ITERATIONS {
+            if left_distance < right_distance {
+                upper = right;
+                (right, right_distance) = (left, left_distance);
+                left = upper - (upper - lower) * inv_phi;
+                left_distance = distance(left)?;
+            } else {
+                lower = left;
+                (left, left_distance) = (right, right_distance);
+                right = lower + (upper - lower) * inv_phi;
+                right_distance = distance(right)?;
+            }
+        }
+
+        // The search assumes a single minimum in the interval, so fall back to the scan if it did worse
+        let refined = (lower + upper) / safe_constant(2.0)?;
+        if distance(refined)? <= best.1 {
+            Ok(refined)
+        } else {
+            safe_constant::<usize, T>(best.0).map(|i| i / denominator)
+        }
+    }
+
+    /// Generate a vector of sampled colours spaced evenly by perceptual distance rather than by position.
+    ///
+    /// Unlike [`ColourMap::sample_n`], which spaces samples evenly along the map, this reparameterises the map
+    /// by its cumulative arc length, as measured by [`ColourMap::perceptual_length`], so the delta-E between consecutive samples is roughly
+    /// constant. Regions where the colour changes slowly receive fewer samples, and rapid transitions more.
+    /// A map with no perceptual change falls back to evenly spaced positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of samples to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `count` is 0, or if sampling or conversion to Lab fails.
+    pub fn sample_perceptual_n(&self, count: usize) -> Result<Vec<C>> {
+        if count == 0 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
+        }
+
+        let table = self.arc_lengths(PERCEPTUAL_SEGMENTS)?;
+        let total = table.last().map_or_else(T::zero, |&(_, length)| length);
+        if count == 1 || total <= T::zero() {
+            return self.sample_n(count);
+        }
+
+        let denominator = safe_constant::<usize, T>(count - 1)?;
+        let mut segment = 0;
+        let mut samples = Vec::with_capacity(count);
+        for i in 0..count {
+            let target = total * safe_constant::<usize, T>(i)? / denominator;
+            while segment < table.len() - 2 && table[segment + 1].1 < target {
+                segment += 1;
+            }
+
+            // Invert the arc length linearly within the segment, skipping segments of zero length
+            let ((pos0, len0), (pos1, len1)) = (table[segment], table[segment + 1]);
+            let position = if len1 > len0 {
+                pos0 + (pos1 - pos0) * ((target - len0) / (len1 - len0)).min(T::one())
+            } else {
+                pos1
+            };
+            samples.push(self.sample(position)?);
+        }
+
+        Ok(samples)
+    }
+
+    /// Measure the total perceptual length of the map, summing the CIE L*a*b* (CIE76) delta-E between
+    /// consecutive evenly spaced samples.
+    ///
+    /// Longer maps span a wider range of perceptually distinct colours, so this can compare how much
+    /// contrast two gradients offer. More segments follow curved paths through Lab more closely.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - Number of segments to divide the map into, must be at least 1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `segments` is 0, or if sampling or conversion to Lab fails.
+    pub fn perceptual_length(&self, segments: usize) -> Result<T> {
+        if segments == 0 {
+            return Err(ColourMapError::InvalidSamplingPosition { position: 0.0 }.into());
+        }
+
+        Ok(self.arc_lengths(segments)?.last().map_or_else(T::zero, |&(_, length)| length))
+    }
+
+    /// Cumulative CIE L*a*b* (CIE76) arc length at each of `segments + 1` evenly spaced positions.
+    fn arc_lengths(&self, segments: usize) -> Result<Vec<(T, T)>> {
+        let mut table = Vec::with_capacity(segments + 1);
+        let mut length = T::zero();
+        let mut previous: Option<Lab<T>> = None;
+        for (position, colour) in self.sampled_pairs(segments + 1)? {
+            let lab = colour.to_lab()?;
+            if let Some(previous) = previous {
+                length = length + previous.delta_e(&lab);
+            }
+            table.push((position, length));
+            previous = Some(lab);
+        }
+
+        Ok(table)
+    }
+
+    /// Quantise pixels to the map's control points, returning the index of the nearest colour for each pixel.
+    ///
+    /// Distances are Euclidean in CIE L*a*b* (CIE76), which is cheap enough for whole images.
+    /// Ties resolve to the lowest index, and alpha components are ignored.
+    /// The palette is converted to Lab once, so nothing is allocated per pixel beyond the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - The colours to quantise
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to Lab fails for any pixel or control point.
+    pub fn quantise(&self, pixels: &[C]) -> Result<Vec<usize>> {
+        if pixels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let palette = self.colours.iter().map(Convert::to_lab).collect::<Result<Vec<_>>>()?;
+
+        pixels
+            .iter()
+            .map(|pixel| Ok(nearest_lab(&pixel.to_lab()?, &palette)))
+            .collect()
+    }
+
+    /// Derive a colour map from the dominant colours of a set of pixels using k-means clustering.
+    ///
+    /// Lloyd's algorithm runs in CIE L*a*b* with CIE76 distances. Centroids are seeded deterministically by
+    /// farthest-point traversal: the first pixel, then repeatedly the pixel farthest from every chosen centroid.
+    /// Iteration stops early once no centroid moves, and clusters left empty keep their previous centroid.
+    /// Alpha components are ignored, and the resulting map is ordered from darkest to lightest centroid.
+    /// Centroids are converted with [`Convert::from_lab`], so Lab maps keep them exactly and other colour spaces
+    /// receive them mapped into the sRGB gamut by chroma reduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - The colours to cluster, must not be empty
+    /// * `k` - The number of clusters, must be at least 1; capped at the number of pixels
+    /// * `iterations` - The maximum number of Lloyd iterations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `k` is 0 or `pixels` is empty, or if conversion to or from Lab fails.
+    pub fn from_pixels_kmeans(pixels: &[C], k: usize, iterations: usize) -> Result<Self> {
+        if k == 0 || pixels.is_empty() {
+            return Err(ColourMapError::EmptyColourMap.into());
+        }
+
+        let labs = pixels.iter().map(Convert::to_lab).collect::<Result<Vec<_>>>()?;
+
+        let mut centroids = vec![labs[0]];
+        let mut distances: Vec<T> = labs.iter().map(|lab| lab.delta_e(&labs[0])).collect();
+        while centroids.len() < k.min(labs.len()) {
+            let mut farthest = 0;
+            for (index, &distance) in distances.iter().enumerate() {
+                if distance > distances[farthest] {
+                    farthest = index;
+                }
+            }
+
+            let centroid = labs[farthest];
+            for (distance, lab) in distances.iter_mut().zip(&labs) {
+                *distance = distance.min(lab.delta_e(&centroid));
+            }
+            centroids.push(centroid);
+        }
+
+        for _ in 0..iterations {
+            let mut sums = vec![([T::zero(); 3], 0_usize); centroids.len()];
+            for lab in &labs {
+                let (sum, count) = &mut sums[nearest_lab(lab, &centroids)];
+                for (total, component) in sum.iter_mut().zip(lab.components()) {
+                    *total = *total + component;
+                }
+                *count += 1;
+            }
+
+            let mut moved = false;
+            for (centroid, &(sum, count)) in centroids.iter_mut().zip(&sums) {
+                if count == 0 {
+                    continue;
+                }
+
+                let count = safe_constant::<usize, T>(count)?;
+                let mean = Lab::from_components_clamped(sum.map(|total| total / count))?;
+                moved |= mean.delta_e(centroid) > T::zero();
+                *centroid = mean;
+            }
+
+            if !moved {
+                break;
+            }
+        }
+
+        centroids.sort_by(|lhs, rhs| lhs.lightness().partial_cmp(&rhs.lightness()).unwrap_or(Ordering::Equal));
+        let colours = centroids.iter().map(C::from_lab).collect::<Result<Vec<_>>>()?;
+        Self::new(&colours)
+    }
+
+    /// Map every control point into the sRGB gamut by reducing its CIE L*a*b* chroma.
+    ///
+    /// Lightness and hue are preserved, and the alpha component is preserved for colour spaces with transparency.
+    /// Only the control points are adjusted, so interpolation in a non-RGB colour space may still pass
+    /// slightly outside the gamut between them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from Lab fails for any control point.
+    pub fn map_to_srgb_gamut(&self) -> Result<Self> {
+        let colours = self
+            .colours
+            .iter()
+            .map(|colour| {
+                let rgb = colour.to_lab()?.map_to_srgb_gamut()?.to_rgb()?;
+                let alpha = colour.to_rgb_alpha()?.alpha();
+                C::from_rgb_alpha(&RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), alpha)?)
+            })
+            .collect::<Result<Vec<C>>>()?;
+
+        Ok(Self::from_parts(colours, self.positions.clone()))
+    }
+
+    /// Serialise the map as an SVG `<linearGradient>` element with one `<stop>` per control point.
+    ///
+    /// Stop offsets are percentages with at most two decimal places, stop colours are sRGB hex strings,
+    /// and a `stop-opacity` is included for colour spaces with transparency.
+    /// Special XML characters in `id` are escaped.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The `id` attribute of the gradient, used to reference it from `fill` or `stroke`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to sRGB fails for any control point.
+    pub fn to_svg_gradient(&self, id: &str) -> Result<String> {
+        let denominator = safe_constant::<usize, f64>(self.colours.len().saturating_sub(1).max(1))?;
+        let offset = |index: usize| -> Result<f64> {
+            match &self.positions {
+                Some(positions) => positions[index].to_f64().ok_or_else(|| {
+                    ColourMapError::PositionOutOfRange {
+                        position: f64::NAN,
+                        index,
+                    }
+                    .into()
+                }),
+                None => Ok(safe_constant::<usize, f64>(index)? / denominator),
+            }
+        };
+
+        let stops = self
+            .colours
+            .iter()
+            .enumerate()
+            .map(|(i, colour)| {
+                let offset = offset(i)?;
+                let hex = colour.to_srgb()?.to_hex()?;
+                let opacity = if C::HAS_ALPHA {
+                    // Quantised to the same precision as the hex colour
+                    let alpha = component_to_u8(colour.to_srgb_alpha()?.alpha(), "alpha", safe_constant(255)?)?;
+                    format!(" stop-opacity=\"{:.3}\"", f64::from(alpha) / 255.0)
+                } else {
+                    String::new()
+                };
+                Ok(format!(
+                    "  <stop offset=\"{}%\" stop-color=\"{hex}\"{opacity}/>\n",
+                    format_percentage(offset * 100.0)
+                ))
+            })
+            .collect::<Result<String>>()?;
+
+        let svg = format!("<linearGradient id=\"{}\">\n{stops}</linearGradient>", escape_xml(id));
+
+        Ok(svg)
+    }
+
+    /// Serialise the map as a GIMP gradient (`.ggr`) file with one linear RGB segment between each pair of control points.
+    ///
+    /// Segment endpoints are the sRGB components and alpha of the control points, with the midpoint halfway between them.
+    /// A single-colour map is written as one flat segment, since GIMP requires at least one.
+    /// Line breaks in `name` are replaced with spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The gradient name shown in GIMP
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to sRGB fails for any control point.
+    pub fn to_ggr(&self, name: &str) -> Result<String> {
+        let position = |index: usize| -> Result<f64> {
+            self.stop_position(index)?.to_f64().ok_or_else(|| {
+                ColourMapError::PositionOutOfRange {
+                    position: f64::NAN,
+                    index,
+                }
+                .into()
+            })
+        };
+        let endpoint = |index: usize| -> Result<String> {
+            let colour = self.colours[index].to_srgb_alpha()?;
+            let component = |value: T| value.to_f64().unwrap_or(f64::NAN);
+            Ok(format!(
+                "{:.6} {:.6} {:.6} {:.6}",
+                component(colour.red()),
+                component(colour.green()),
+                component(colour.blue()),
+                component(colour.alpha())
+            ))
+        };
+
+        let segment = |left: f64, right: f64, start: usize, end: usize| -> Result<String> {
+            Ok(format!(
+                "{left:.6} {:.6} {right:.6} {} {} 0 0 0 0\n",
+                f64::midpoint(left, right),
+                endpoint(start)?,
+                endpoint(end)?
+            ))
+        };
+
+        let segments = if self.colours.len() > 1 {
+            (1..self.colours.len())
+                .map(|i| segment(position(i - 1)?, position(i)?, i - 1, i))
+                .collect::<Result<Vec<String>>>()?
+        } else {
+            vec![segment(0.0, 1.0, 0, 0)?]
+        };
+
+        let name = name.replace(['\r', '\n'], " ");
+        Ok(format!(
+            "GIMP Gradient\nName: {name}\n{}\n{}",
+            segments.len(),
+            segments.concat()
+        ))
+    }
+
+    /// Render the map as a row-major RGBA8 pixel buffer of `width` by `height` pixels.
+    ///
+    /// Column `x` samples position `x / (width - 1)`, or position 0 when `width` is 1, and every row is identical.
+    /// Pixels are sRGB-encoded, with full opacity for colour spaces without transparency.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of columns, must be at least 1
+    /// * `height` - The number of rows
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `width` is 0, or if sampling or conversion to sRGB fails.
+    pub fn to_rgba_strip(&self, width: usize, height: usize) -> Result<Vec<u8>> {
+        let row = self
+            .sample_n(width)?
+            .iter()
+            .map(|colour| colour.to_srgb_alpha()?.to_bytes())
+            .collect::<Result<Vec<[u8; 4]>>>()?
+            .concat();
+
+        Ok(row.repeat(height))
+    }
+
+    /// Gamma-encode the RGB channels of each control point (`component^(1 / gamma)`).
+    /// The alpha component is preserved for colour spaces with transparency.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamma` - The gamma exponent, must be positive and finite
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `gamma` is not positive and finite,
+    /// or if conversion to or from RGB fails for any control point.
+    pub fn apply_gamma(&self, gamma: T) -> Result<Self> {
+        validate_gamma(gamma)?;
+        self.map_channels(|component| component.powf(gamma.recip()))
+    }
+
+    /// Gamma-decode the RGB channels of each control point (`component^gamma`).
+    /// The alpha component is preserved for colour spaces with transparency.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamma` - The gamma exponent, must be positive and finite
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `gamma` is not positive and finite,
+    /// or if conversion to or from RGB fails for any control point.
+    pub fn remove_gamma(&self, gamma: T) -> Result<Self> {
+        validate_gamma(gamma)?;
+        self.map_channels(|component| component.powf(gamma))
+    }
+
+    /// Apply a function to each linear RGB channel of every control point, preserving alpha.
+    fn map_channels<F>(&self, f: F) -> Result<Self>
+    where
+        F: Fn(T) -> T,
+    {
+        self.map_rgb_alpha(|colour| RgbAlpha::new(f(colour.red()), f(colour.green()), f(colour.blue()), colour.alpha()))
+    }
+
+    /// Apply a transformation to every control point via the `RgbAlpha` colour space.
+    fn map_rgb_alpha<F>(&self, f: F) -> Result<Self>
+    where
+        F: Fn(&RgbAlpha<T>) -> Result<RgbAlpha<T>>,
+    {
+        let colours = self
+            .colours
+            .iter()
+            .map(|colour| C::from_rgb_alpha(&f(&colour.to_rgb_alpha()?)?))
+            .collect::<Result<Vec<C>>>()?;
+
+        Ok(Self::from_parts(colours, self.positions.clone()))
+    }
+}
+
+/// Index of the Lab colour in `palette` nearest to `lab` by CIE76 distance, resolving ties to the lowest index.
+fn nearest_lab<T: Float + Send + Sync>(lab: &Lab<T>, palette: &[Lab<T>]) -> usize {
+    let mut nearest = (0, T::infinity());
+    for (index, colour) in palette.iter().enumerate() {
+        let distance = lab.delta_e(colour);
+        if distance < nearest.1 {
+            nearest = (index, distance);
+        }
+    }
+    nearest.0
+}
+
+/// Format a percentage to two decimal places, trimming trailing zeros (e.g. `10`, `33.33`, `12.5`).
+fn format_percentage(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        String::from("0")
+    } else {
+        String::from(trimmed)
+    }
+}
+
+/// Escape the characters that are not allowed verbatim in an XML attribute value.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+impl<T: Float + Send + Sync> LabMap<T> {
+    /// Create a single-hue sequential map that is perceptually uniform in lightness.
+    ///
+    /// Lightness steps uniformly from dark to light, while chroma follows a gentle arc
+    /// peaking in the middle of the map so both ends stay close to neutral.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The hue angle in degrees, measured in the a*b* plane
+    /// * `num_colours` - Number of control points to generate, must not be zero
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `num_colours` is zero or if constant conversion fails.
+    pub fn sequential(hue: T, num_colours: usize) -> Result<Self> {
+        let (min_lightness, max_lightness) = (safe_constant::<f64, T>(15.0)?, safe_constant::<f64, T>(95.0)?);
+        let (min_chroma, max_chroma) = (safe_constant::<f64, T>(5.0)?, safe_constant::<f64, T>(45.0)?);
+        let pi = safe_constant::<f64, T>(core::f64::consts::PI)?;
+        let (sin_hue, cos_hue) = hue.to_radians().sin_cos();
+
+        Self::from_fn(num_colours, |t| {
+            let lightness = min_lightness + (max_lightness - min_lightness) * t;
+            let chroma = min_chroma + (max_chroma - min_chroma) * (pi * t).sin();
+            Lab::new(lightness, chroma * cos_hue, chroma * sin_hue)
+        })
+    }
+}
+
+impl<T: Float + Send + Sync> RgbMap<T> {
+    /// Create a symmetric diverging map with three evenly spaced stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The colour at position 0
+    /// * `mid` - The colour at position 0.5
+    /// * `high` - The colour at position 1
+    ///
+    /// # Errors
+    ///
+    /// This function currently does not fail, but returns a `Result` for consistency with other constructors.
+    pub fn diverging(low: Rgb<T>, mid: Rgb<T>, high: Rgb<T>) -> Result<Self> {
+        Self::new(&[low, mid, high])
+    }
+
+    /// Create a symmetric diverging map whose two ends have equal perceptual lightness.
+    ///
+    /// The `low` and `high` colours are moved to the mean of their CIE L*a*b* lightness values,
+    /// keeping their a* and b* components, so neither half of the map dominates visually.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The colour at position 0, before balancing
+    /// * `mid` - The colour at position 0.5
+    /// * `high` - The colour at position 1, before balancing
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from Lab fails.
+    pub fn diverging_balanced(low: Rgb<T>, mid: Rgb<T>, high: Rgb<T>) -> Result<Self> {
+        let (low_lab, high_lab) = (low.to_lab()?, high.to_lab()?);
+        let lightness = (low_lab.lightness() + high_lab.lightness()) / safe_constant(2.0)?;
+
+        let low = Lab::new(lightness, low_lab.a_star(), low_lab.b_star())?.to_rgb()?;
+        let high = Lab::new(lightness, high_lab.a_star(), high_lab.b_star())?.to_rgb()?;
+
+        Self::diverging(low, mid, high)
+    }
+}
+
+impl<C, T, const N: usize> Display for ColourMap<C, T, N>
+where
+    C: Display + Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        #[cfg(feature = "std")]
+        let width = terminal_size().map_or(DISPLAY_WIDTH, |(Width(w), _)| w).min(200); // Cap at reasonable width
+        #[cfg(not(feature = "std"))]
+        let width = DISPLAY_WIDTH;
+
+        let denom = width.saturating_sub(1).max(1);
+
+        for i in 0..width {
+            // Use our safe conversion helpers
+            let position = match (safe_constant::<u16, T>(i), safe_constant::<u16, T>(denom)) {
+                (Ok(i_t), Ok(denom_t)) => i_t / denom_t,
+                _ => return Err(core::fmt::Error),
+            };
+
+            match self.sample(position) {
+                Ok(colour) => write!(fmt, "{colour:#}")?,
+                Err(_) => return Err(core::fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+// Implement IntoIterator for easier usage
+impl<C, T, const N: usize> IntoIterator for ColourMap<C, T, N>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    type Item = C;
+    type IntoIter = vec::IntoIter<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colours.into_iter()
+    }
+}
+
+impl<'a, C, T, const N: usize> IntoIterator for &'a ColourMap<C, T, N>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    type Item = &'a C;
+    type IntoIter = core::slice::Iter<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colours.iter()
+    }
+}
+
+/// Access a control point by index.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds, like `Vec`.
+impl<C, T, const N: usize> Index<usize> for ColourMap<C, T, N>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    type Output = C;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.colours[index]
+    }
+}
+
+/// Mutably access a control point by index.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds, like `Vec`.
+impl<C, T, const N: usize> IndexMut<usize> for ColourMap<C, T, N>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.colours[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ChromaticError;
+
+    #[test]
+    fn svg_gradient_offsets_use_fixed_precision() -> Result<()> {
+        let colours = (0..11)
+            .map(|i| Rgb::<f64>::new(f64::from(i) / 10.0, 0.0, 0.0))
+            .collect::<Result<Vec<_>>>()?;
+        let svg = RgbMap::new(&colours)?.to_svg_gradient("ramp")?;
+
+        let offsets = svg
+            .split("offset=\"")
+            .skip(1)
+            .filter_map(|rest| rest.split('"').next())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            offsets,
+            ["0%", "10%", "20%", "30%", "40%", "50%", "60%", "70%", "80%", "90%", "100%"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn svg_gradient_rounds_repeating_offsets() -> Result<()> {
+        let colours = [
+            Rgb::<f64>::new(0.0, 0.0, 0.0)?,
+            Rgb::new(0.5, 0.5, 0.5)?,
+            Rgb::new(0.7, 0.7, 0.7)?,
+            Rgb::new(1.0, 1.0, 1.0)?,
+        ];
+        let svg = RgbMap::new(&colours)?.to_svg_gradient("grey")?;
+
+        assert!(svg.contains("offset=\"33.33%\""));
+        assert!(svg.contains("offset=\"66.67%\""));
+        Ok(())
+    }
+
+    #[test]
+    fn hsl_map_interpolates_hue_through_zero() -> Result<()> {
+        let map = HslMap::new(&[Hsl::<f64>::new(350.0, 1.0, 0.5)?, Hsl::new(10.0, 1.0, 0.5)?])?;
+
+        let mid = map.sample(0.5)?;
+        let hue_distance_from_zero = mid.hue().min(360.0 - mid.hue());
+        assert!(hue_distance_from_zero < 1e-9, "midpoint hue was {}", mid.hue());
+        Ok(())
+    }
+
+    #[test]
+    fn hsv_map_interpolates_hue_through_zero() -> Result<()> {
+        let map = HsvMap::new(&[Hsv::<f64>::new(350.0, 1.0, 1.0)?, Hsv::new(10.0, 1.0, 1.0)?])?;
+
+        let mid = map.sample(0.5)?;
+        let hue_distance_from_zero = mid.hue().min(360.0 - mid.hue());
+        assert!(hue_distance_from_zero < 1e-9, "midpoint hue was {}", mid.hue());
+        Ok(())
+    }
+
+    #[test]
+    fn sampled_pairs_are_evenly_spaced_and_match_sample() -> Result<()> {
+        let map = RgbMap::new(&[Rgb::<f64>::new(0.0, 0.2, 1.0)?, Rgb::new(1.0, 0.6, 0.0)?])?;
+
+        let pairs = map.sampled_pairs(5)?;
+        assert_eq!(pairs.len(), 5);
+        for ((position, colour), expected_position) in pairs.iter().zip([0.0, 0.25, 0.5, 0.75, 1.0]) {
+            assert!((position - expected_position).abs() < 1e-12);
+
+            let expected = map.sample(*position)?;
+            for (lhs, rhs) in colour.components().iter().zip(expected.components()) {
+                assert!((lhs - rhs).abs() < 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn negate_swaps_black_and_white() -> Result<()> {
+        let map = RgbMap::new(&[Rgb::<f64>::new(0.0, 0.0, 0.0)?, Rgb::new(1.0, 1.0, 1.0)?])?;
+
+        let negated = map.negate()?;
+        assert!(negated.sample(0.0)?.components().iter().all(|c| (c - 1.0).abs() < 1e-12));
+        assert!(negated.sample(1.0)?.components().iter().all(|c| c.abs() < 1e-12));
+        Ok(())
+    }
+
+    #[test]
+    fn double_negation_restores_the_map_and_alpha() -> Result<()> {
+        let map = RgbAlphaMap::new(&[RgbAlpha::<f64>::new(0.1, 0.5, 0.9, 0.25)?, RgbAlpha::new(0.7, 0.3, 0.2, 1.0)?])?;
+
+        let negated = map.negate()?;
+        assert!((negated.sample(0.0)?.alpha() - 0.25).abs() < 1e-12);
+
+        let restored = negated.negate()?;
+        for position in [0.0, 0.5, 1.0] {
+            let (lhs, rhs) = (restored.sample(position)?, map.sample(position)?);
+            for (a, b) in lhs.components().iter().zip(rhs.components()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_fn_control_points_match_the_function() -> Result<()> {
+        let rainbow = |t: f64| Hsl::new(t * 300.0, 1.0, 0.5);
+        let map = HslMap::from_fn(4, rainbow)?;
+
+        assert_eq!(map.len(), 4);
+        for (colour, position) in map.colours().iter().zip([0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]) {
+            assert!((colour.hue() - rainbow(position)?.hue()).abs() < 1e-9);
+        }
+        assert!(HslMap::from_fn(0, rainbow).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn from_stops_str_places_colours_at_their_stops() -> Result<()> {
+        let map = RgbMap::<f64>::from_stops_str(" 0.0:#000000,0.5: #ff0000 , 1.0:#ffffff ")?;
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.sample(0.0)?.to_hex()?, "#000000");
+        assert_eq!(map.sample(0.5)?.to_hex()?, "#FF0000");
+        assert_eq!(map.sample(1.0)?.to_hex()?, "#FFFFFF");
+        Ok(())
+    }
+
+    #[test]
+    fn from_stops_str_rejects_out_of_order_positions() {
+        assert!(RgbMap::<f64>::from_stops_str("0.0:#000000, 0.8:#ff0000, 0.5:#ffffff").is_err());
+        assert!(RgbMap::<f64>::from_stops_str("").is_err());
+    }
+
+    #[test]
+    fn apply_then_remove_gamma_restores_the_map() -> Result<()> {
+        let map = RgbMap::new(&[Rgb::<f64>::new(0.04, 0.5, 0.9)?, Rgb::new(0.2, 0.7, 0.1)?])?;
+
+        let encoded = map.apply_gamma(2.2)?;
+        assert!((encoded[0].green() - 0.5_f64.powf(1.0 / 2.2)).abs() < 1e-12);
+
+        let restored = encoded.remove_gamma(2.2)?;
+        for (lhs, rhs) in restored.colours().iter().zip(map.colours()) {
+            for (a, b) in lhs.components().iter().zip(rhs.components()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+        assert!(map.apply_gamma(0.0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn contrasting_foreground_flips_from_white_to_black() -> Result<()> {
+        let background = RgbMap::new(&[
+            Rgb::<f64>::new(0.0, 0.0, 0.0)?,
+            Rgb::new(0.1, 0.1, 0.3)?,
+            Rgb::new(0.8, 0.8, 0.6)?,
+            Rgb::new(1.0, 1.0, 1.0)?,
+        ])?;
+
+        let foreground = background.contrasting_foreground()?;
+        for (colour, expected) in foreground.colours().iter().zip([1.0, 1.0, 0.0, 0.0]) {
+            assert!(colour.components().iter().all(|c| (c - expected).abs() < 1e-12));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn index_mut_updates_sampled_colours() -> Result<()> {
+        let mut map = RgbMap::new(&[Rgb::<f64>::new(0.0, 0.0, 0.0)?, Rgb::new(1.0, 1.0, 1.0)?])?;
+        assert!(map[1].red() > 0.99);
+
+        map[1] = Rgb::new(0.0, 0.0, 1.0)?;
+        let mid = map.sample(0.5)?;
+        assert!(mid.red().abs() < 1e-12);
+        assert!((mid.blue() - 0.5).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_panics_out_of_bounds() {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0).expect("valid grey")]).expect("valid map");
+        assert!(map[1].components()[0] >= 0.0);
+    }
+
+    #[test]
+    fn positions_at_luminance_finds_single_crossing() -> Result<()> {
+        let map = RgbMap::new(&[Rgb::<f64>::new(0.0, 0.0, 0.0)?, Rgb::new(1.0, 1.0, 1.0)?])?;
+
+        let positions = map.positions_at_luminance(0.5, 64)?;
+        assert_eq!(positions.len(), 1);
+        assert!((positions[0] - 0.5).abs() < 1e-3);
+        assert!(map.positions_at_luminance(0.5, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn positions_at_luminance_finds_every_crossing() -> Result<()> {
+        let map = GreyMap::new(&[Grey::<f64>::new(0.0)?, Grey::new(1.0)?, Grey::new(0.0)?])?;
+
+        let positions = map.positions_at_luminance(0.25, 101)?;
+        assert_eq!(positions.len(), 2);
+        assert!(positions[0] < 0.5 && positions[1] > 0.5);
+        Ok(())
+    }
+
+    #[test]
+    fn sequential_map_luminance_increases_monotonically() -> Result<()> {
+        let map = LabMap::<f64>::sequential(250.0, 7)?;
+
+        assert_eq!(map.len(), 7);
+        let luminances = map
+            .sample_n(50)?
+            .iter()
+            .map(|colour| Ok(colour.to_xyz()?.y()))
+            .collect::<Result<Vec<f64>>>()?;
+        assert!(luminances.windows(2).all(|pair| pair[1] > pair[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn diverging_map_passes_through_mid() -> Result<()> {
+        let (low, mid, high) = (
+            Rgb::<f64>::new(0.0, 0.1, 0.6)?,
+            Rgb::new(1.0, 1.0, 1.0)?,
+            Rgb::new(0.7, 0.05, 0.0)?,
+        );
+        let map = RgbMap::diverging(low, mid, high)?;
+
+        let centre = map.sample(0.5)?;
+        for (lhs, rhs) in centre.components().iter().zip(mid.components()) {
+            assert!((lhs - rhs).abs() < 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn diverging_balanced_equalises_end_lightness() -> Result<()> {
+        let (low, mid, high) = (
+            Rgb::<f64>::new(0.02, 0.05, 0.4)?,
+            Rgb::new(1.0, 1.0, 1.0)?,
+            Rgb::new(0.8, 0.3, 0.1)?,
+        );
+        let unbalanced = low.to_lab()?.lightness() - high.to_lab()?.lightness();
+        assert!(unbalanced.abs() > 10.0);
+
+        let map = RgbMap::diverging_balanced(low, mid, high)?;
+        let (start, end) = (map.sample(0.0)?.to_lab()?, map.sample(1.0)?.to_lab()?);
+        assert!((start.lightness() - end.lightness()).abs() < 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn map_to_srgb_gamut_keeps_samples_in_gamut() -> Result<()> {
+        let map = LabMap::new(&[Lab::<f64>::new(50.0, 90.0, -100.0)?, Lab::new(85.0, -100.0, 90.0)?])?;
+        assert!(!map.sample(0.0)?.in_srgb_gamut());
+
+        let mapped = map.map_to_srgb_gamut()?;
+        for colour in mapped.colours() {
+            assert!(colour.in_srgb_gamut());
+        }
+        for colour in mapped.sample_n(33)? {
+            assert!(colour.to_xyz()?.in_srgb_gamut());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn full_subrange_reproduces_the_map() -> Result<()> {
+        let map = RgbMap::new(&[
+            Rgb::<f64>::new(0.0, 0.0, 0.5)?,
+            Rgb::new(0.9, 0.1, 0.0)?,
+            Rgb::new(1.0, 1.0, 0.2)?,
+        ])?;
+
+        let copy = map.subrange(0.0, 1.0, map.len())?;
+        for position in [0.0, 0.1, 0.5, 0.77, 1.0] {
+            let (lhs, rhs) = (copy.sample(position)?, map.sample(position)?);
+            for (a, b) in lhs.components().iter().zip(rhs.components()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn subrange_rescales_positions() -> Result<()> {
+        let map = GreyMap::new(&[Grey::<f64>::new(0.0)?, Grey::new(1.0)?])?;
+
+        let slice = map.subrange(0.2, 0.6, 3)?;
+        assert!((slice.sample(0.0)?.components()[0] - 0.2).abs() < 1e-9);
+        assert!((slice.sample(1.0)?.components()[0] - 0.6).abs() < 1e-9);
+        assert!(map.subrange(0.6, 0.2, 3).is_err());
+        assert!(map.subrange(0.0, 1.5, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn colour_map_macro_matches_manual_construction() -> Result<()> {
+        let from_macro = colour_map![RgbMap<f64>; "#000000", "#FF0000" @ 0.3, "#FFFFFF"]?;
+        let manual = RgbMap::from_hex_stops(&[("#000000", Some(0.0)), ("#FF0000", Some(0.3)), ("#FFFFFF", Some(1.0))])?;
+
+        assert_eq!(from_macro.positions(), manual.positions());
+        for position in [0.0, 0.15, 0.3, 0.65, 1.0] {
+            assert_eq!(from_macro.sample(position)?.to_hex()?, manual.sample(position)?.to_hex()?);
+        }
+        assert!(colour_map![RgbMap<f64>; "#000000", "not a colour"].is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn lerp_map_averages_control_points() -> Result<()> {
+        let greys = RgbMap::new(&[Rgb::<f64>::new(0.0, 0.0, 0.0)?, Rgb::new(1.0, 1.0, 1.0)?])?;
+        let warm_cool = RgbMap::new(&[Rgb::<f64>::new(1.0, 0.0, 0.0)?, Rgb::new(0.0, 0.0, 1.0)?])?;
+
+        let faded = greys.lerp_map(&warm_cool, 0.5)?;
+        assert_eq!(faded.len(), 2);
+        for (colour, expected) in faded.colours().iter().zip([[0.5, 0.0, 0.0], [0.5, 0.5, 1.0]]) {
+            for (lhs, rhs) in colour.components().iter().zip(expected) {
+                assert!((lhs - rhs).abs() < 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lerp_map_resamples_maps_of_different_lengths() -> Result<()> {
+        let two = GreyMap::new(&[Grey::<f64>::new(0.0)?, Grey::new(1.0)?])?;
+        let three = GreyMap::new(&[Grey::<f64>::new(1.0)?, Grey::new(1.0)?, Grey::new(1.0)?])?;
+
+        let faded = two.lerp_map(&three, 0.5)?;
+        assert_eq!(faded.len(), 3);
+        assert!((faded.sample(0.5)?.components()[0] - 0.75).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_extrapolated_continues_end_slopes_and_clamps() -> Result<()> {
+        let map = GreyMap::new(&[Grey::<f64>::new(0.2)?, Grey::new(0.6)?])?;
+
+        let value = |position: f64| -> Result<f64> { Ok(map.sample_extrapolated(position)?.components()[0]) };
+        assert!((value(-0.25)? - 0.1).abs() < 1e-12);
+        assert!((value(1.5)? - 0.8).abs() < 1e-12);
+        assert!((value(0.5)? - 0.4).abs() < 1e-12);
+
+        // Continuing the slope to -1 would give -0.2, darker than black, so the result is clamped
+        assert!(value(-1.0)?.abs() < 1e-12);
+        assert!(map.sample_extrapolated(f64::NAN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn locate_inverts_sampling() -> Result<()> {
+        let map = LabMap::new(&[
+            Lab::<f64>::new(20.0, 10.0, -40.0)?,
+            Lab::new(60.0, 50.0, 10.0)?,
+            Lab::new(95.0, -5.0, 60.0)?,
+        ])?;
+
+        for position in [0.0, 0.37, 0.5, 0.81, 1.0] {
+            let located = map.locate(&map.sample(position)?, 32)?;
+            assert!((located - position).abs() < 1e-3, "{located} != {position}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sample_unchecked_matches_sample() -> Result<()> {
+        let map = RgbMap::from_positions(&[
+            (Rgb::<f64>::new(0.0, 0.0, 0.3)?, 0.0),
+            (Rgb::new(0.8, 0.1, 0.1)?, 0.2),
+            (Rgb::new(1.0, 1.0, 0.0)?, 1.0),
+        ])?;
+
+        for i in 0..=100 {
+            let position = f64::from(i) / 100.0;
+            let (checked, unchecked) = (map.sample(position)?, map.sample_unchecked(position));
+            for (lhs, rhs) in checked.components().iter().zip(unchecked.components()) {
+                assert!((lhs - rhs).abs() < 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn one_stop_map_samples_its_colour_everywhere() -> Result<()> {
+        let colour = Rgb::<f64>::new(0.2, 0.4, 0.6)?;
+        let map = RgbMap::from_positions(&[(colour, 0.3)])?;
+
+        for position in [0.0, 0.3, 0.7, 1.0] {
+            assert_eq!(map.sample(position)?, colour);
+            assert_eq!(map.sample_with(position, |lhs, _, _| Ok(*lhs))?, colour);
+        }
+        assert!(map.sample_n(4)?.iter().all(|sample| *sample == colour));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_stop_positions_are_rejected_as_degenerate() -> Result<()> {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0)?;
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0)?;
+
+        let result = RgbMap::from_positions(&[(black, 0.0), (white, 0.0), (black, 1.0)]);
+        assert!(matches!(
+            result,
+            Err(ChromaticError::ColourMap(ColourMapError::DegenerateSpacing {
+                idx1: 0,
+                idx2: 1,
+                ..
+            }))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn reversed_lab_map_mirrors_sampling() -> Result<()> {
+        let map = LabMap::<f64>::new(&[
+            Lab::new(10.0, 20.0, -30.0)?,
+            Lab::new(40.0, -10.0, 15.0)?,
+            Lab::new(70.0, 5.0, 40.0)?,
+            Lab::new(95.0, -2.0, 3.0)?,
+        ])?;
+        let reversed = map.reversed();
+        let mut in_place = map.clone();
+        in_place.reverse();
+
+        for position in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let expected = map.sample(1.0 - position)?;
+            for sample in [reversed.sample(position)?, in_place.sample(position)?] {
+                for (lhs, rhs) in sample.components().iter().zip(expected.components()) {
+                    assert!((lhs - rhs).abs() < 1e-9);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_positions_are_honoured_when_sampling() -> Result<()> {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0)?;
+        let grey = Rgb::<f64>::new(0.5, 0.5, 0.5)?;
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0)?;
+        let map = RgbMap::from_positions(&[(black, 0.0), (grey, 0.1), (white, 1.0)])?;
+
+        // Halfway between the first two stops, rather than at uniform thirds
+        let sample = map.sample(0.05)?;
+        assert!(sample.components().iter().all(|component| (component - 0.25).abs() < 1e-9));
+        assert_eq!(map.positions(), Some([0.0, 0.1, 1.0].as_slice()));
+        assert_eq!(RgbMap::new(&[black, white])?.positions(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn cubic_grey_ramp_is_monotonic() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.2)?, Grey::new(1.0)?])?;
+
+        let mut previous = map.sample_cubic(0.0)?.grey();
+        assert!(previous.abs() < 1e-12);
+        for step in 1..=100 {
+            let current = map.sample_cubic(f64::from(step) / 100.0)?.grey();
+            assert!(current >= previous - 1e-12);
+            previous = current;
+        }
+        assert!((previous - 1.0).abs() < 1e-12);
+        assert!((map.sample_cubic(0.5)?.grey() - 0.2).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn concat_joins_maps_uniformly_and_continuously() -> Result<()> {
+        let first = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.4)?])?;
+        let second = GreyMap::<f64>::new(&[Grey::new(0.6)?, Grey::new(0.8)?, Grey::new(1.0)?])?;
+
+        let joined = first.concat(&second);
+        assert_eq!(joined.len(), 5);
+        assert_eq!(joined.positions(), None);
+
+        // The join sits between the second and third control points, at positions 0.25 and 0.5
+        for boundary in [0.25, 0.5] {
+            let before = joined.sample(boundary - 1e-9)?.grey();
+            let after = joined.sample(boundary + 1e-9)?.grey();
+            assert!((before - after).abs() < 1e-6);
+        }
+        assert!((joined.sample(0.5)?.grey() - 0.6).abs() < 1e-12);
+
+        let mut pushed = first;
+        pushed.push(Grey::new(1.0)?);
+        assert_eq!(pushed.len(), 3);
+        assert!((pushed.sample(0.5)?.grey() - 0.4).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn quantise_maps_gradient_pixels_to_nearest_entries() -> Result<()> {
+        let palette = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.33)?, Grey::new(0.66)?, Grey::new(1.0)?])?;
+        // Distances are perceptual, so dark linear greys sit further from black than their values suggest
+        let pixels = [0.0, 0.02, 0.3, 0.45, 0.6, 0.9, 1.0].map(|grey| Grey::new(grey).expect("grey is in range"));
+
+        assert_eq!(palette.quantise(&pixels)?, [0, 0, 1, 1, 2, 3, 3]);
+        assert!(palette.quantise(&[])?.is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_sample_n_matches_sample_n() -> Result<()> {
+        let map = LabMap::<f64>::new(&[
+            Lab::new(20.0, 40.0, -60.0)?,
+            Lab::new(60.0, -30.0, 20.0)?,
+            Lab::new(90.0, 5.0, 70.0)?,
+        ])?;
+
+        let parallel = map.par_sample_n(1000)?;
+        let sequential = map.sample_n(1000)?;
+        assert_eq!(parallel.len(), sequential.len());
+        for (lhs, rhs) in parallel.iter().zip(&sequential) {
+            assert_eq!(lhs.components().map(f64::to_bits), rhs.components().map(f64::to_bits));
+        }
+        assert!(map.par_sample_n(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ggr_output_has_one_segment_per_gap() -> Result<()> {
+        let colours = [
+            Srgb::<f64>::new(1.0, 0.0, 0.0)?,
+            Srgb::new(0.0, 0.5, 0.0)?,
+            Srgb::new(0.0, 0.0, 0.25)?,
+        ];
+        let map = SrgbMap::new(&colours)?;
+
+        let ggr = map.to_ggr("Test\nGradient")?;
+        let lines: Vec<&str> = ggr.lines().collect();
+        assert_eq!(lines[..2], ["GIMP Gradient", "Name: Test Gradient"]);
+        assert_eq!(lines[2].parse::<usize>().expect("segment count"), map.len() - 1);
+
+        let segments: Vec<Vec<f64>> = lines[3..]
+            .iter()
+            .map(|line| line.split_whitespace().map(|value| value.parse().expect("number")).collect())
+            .collect();
+        assert_eq!(segments.len(), 2);
+        for (i, segment) in segments.iter().enumerate() {
+            assert_eq!(segment.len(), 15);
+            let (left, right) = (&segment[3..6], &segment[7..10]);
+            for (value, expected) in left.iter().zip(colours[i].components()) {
+                assert!((value - expected).abs() < 1e-6);
+            }
+            for (value, expected) in right.iter().zip(colours[i + 1].components()) {
+                assert!((value - expected).abs() < 1e-6);
+            }
+            assert!((segment[0] - [0.0, 0.5][i]).abs() < 1e-6 && (segment[2] - [0.5, 1.0][i]).abs() < 1e-6);
+            assert!((segment[6] - 1.0).abs() < 1e-6 && (segment[10] - 1.0).abs() < 1e-6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn svg_gradient_has_a_stop_per_colour() -> Result<()> {
+        let map = SrgbMap::<f64>::new(&[
+            Srgb::new(1.0, 0.0, 0.0)?,
+            Srgb::new(0.0, 1.0, 0.0)?,
+            Srgb::new(0.0, 0.0, 1.0)?,
+        ])?;
+
+        let svg = map.to_svg_gradient("rgb")?;
+        assert!(svg.starts_with("<linearGradient id=\"rgb\">"));
+        assert_eq!(svg.matches("<stop ").count(), 3);
+        for (offset, colour) in [("0%", "#FF0000"), ("50%", "#00FF00"), ("100%", "#0000FF")] {
+            assert!(svg.contains(&format!("offset=\"{offset}\" stop-color=\"{colour}\"")));
+        }
+        assert!(!svg.contains("stop-opacity"));
+        Ok(())
+    }
+
+    #[test]
+    fn svg_gradient_emits_opacity_for_alpha_maps() -> Result<()> {
+        let map = SrgbAlphaMap::<f64>::new(&[SrgbAlpha::new(1.0, 0.0, 0.0, 1.0)?, SrgbAlpha::new(0.0, 0.0, 1.0, 0.0)?])?;
+
+        let svg = map.to_svg_gradient("fade")?;
+        assert!(svg.contains("stop-opacity=\"1.000\""));
+        assert!(svg.contains("stop-opacity=\"0.000\""));
+        Ok(())
+    }
+
+    #[test]
+    fn rgba_strip_columns_span_the_map() -> Result<()> {
+        let map = SrgbMap::<f64>::new(&[Srgb::new(1.0, 0.0, 0.0)?, Srgb::new(0.0, 0.0, 1.0)?])?;
+
+        let (width, height) = (8, 3);
+        let strip = map.to_rgba_strip(width, height)?;
+        assert_eq!(strip.len(), width * height * 4);
+        for row in strip.chunks_exact(width * 4) {
+            assert_eq!(row[..4], [255, 0, 0, 255]);
+            assert_eq!(row[row.len() - 4..], [0, 0, 255, 255]);
+        }
+
+        assert_eq!(map.to_rgba_strip(1, 2)?, [255, 0, 0, 255, 255, 0, 0, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn cyclic_sampling_wraps_positions() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.5)?, Grey::new(1.0)?])?;
+
+        let quarter = map.sample_cyclic(0.25)?.grey();
+        assert!((quarter - 0.375).abs() < 1e-12);
+        assert!((map.sample_cyclic(1.25)?.grey() - quarter).abs() < 1e-12);
+        assert!((map.sample_cyclic(-0.75)?.grey() - quarter).abs() < 1e-12);
+        assert!(map.sample_cyclic(1.0)?.grey().abs() < 1e-12);
+        assert!(map.sample_cyclic(f64::NAN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cyclic_sampling_of_uniform_maps_has_no_seam() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.5)?, Grey::new(1.0)?])?;
+
+        // The colours sit at 0, 1/3 and 2/3, and the final third runs from white back to black
+        assert!((map.sample_cyclic(1.0 / 3.0)?.grey() - 0.5).abs() < 1e-12);
+        assert!((map.sample_cyclic(5.0 / 6.0)?.grey() - 0.5).abs() < 1e-12);
+        assert!((map.sample_cyclic(0.999)?.grey() - map.sample_cyclic(0.0)?.grey()).abs() < 1e-2);
+        Ok(())
+    }
+
+    #[test]
+    fn cyclic_sampling_joins_last_and_first_stops() -> Result<()> {
+        let map = GreyMap::<f64>::from_positions(&[(Grey::new(0.2)?, 0.25), (Grey::new(0.6)?, 0.75)])?;
+
+        // Position 0 is halfway across the gap from the last stop back round to the first
+        assert!((map.sample_cyclic(0.0)?.grey() - 0.4).abs() < 1e-12);
+        assert!((map.sample_cyclic(0.5)?.grey() - 0.4).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn mirror_sampling_folds_like_a_triangle_wave() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(1.0)?])?;
+
+        for (position, expected) in [(1.2, 0.8), (1.5, 0.5), (2.0, 0.0), (2.5, 0.5), (-0.3, 0.3)] {
+            assert!((map.sample_mirror(position)?.grey() - expected).abs() < 1e-12);
+        }
+        assert!(map.sample_mirror(f64::INFINITY).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn eased_sampling_remaps_positions() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.5)?, Grey::new(1.0)?])?;
+
+        for position in [0.0, 0.3, 0.7, 1.0] {
+            assert_eq!(
+                map.sample_eased(position, Easing::Linear)?.grey().to_bits(),
+                map.sample(position)?.grey().to_bits()
+            );
+        }
+        assert!((map.sample_eased(0.5, Easing::SmoothStep)?.grey() - map.sample(0.5)?.grey()).abs() < 1e-12);
+        assert!((map.sample_eased(0.25, Easing::SmoothStep)?.grey() - map.sample(0.25)?.grey()).abs() > 0.05);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn display_uses_fixed_width_without_std() -> Result<()> {
+        let map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(1.0)?])?;
+
+        let rendered = format!("{map}");
+        assert_eq!(
+            rendered.matches(crate::config::PRINT_BLOCK).count(),
+            usize::from(DISPLAY_WIDTH)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn perceptual_sampling_evens_out_steps() -> Result<()> {
+        // Half the lightness range is squeezed into the first tenth of the map
+        let map = LabMap::<f64>::from_positions(&[
+            (Lab::new(0.0, 0.0, 0.0)?, 0.0),
+            (Lab::new(50.0, 0.0, 0.0)?, 0.1),
+            (Lab::new(100.0, 0.0, 0.0)?, 1.0),
+        ])?;
+
+        let spread = |samples: &[Lab<f64>]| {
+            let steps = samples.windows(2).map(|pair| pair[0].delta_e(&pair[1])).collect::<Vec<_>>();
+            let max = steps.iter().copied().fold(f64::MIN, f64::max);
+            let min = steps.iter().copied().fold(f64::MAX, f64::min);
+            max - min
+        };
+
+        let perceptual = map.sample_perceptual_n(21)?;
+        let linear = map.sample_n(21)?;
+        assert_eq!(perceptual.len(), 21);
+        assert!(spread(&perceptual) < 0.5);
+        assert!(spread(&perceptual) < spread(&linear) / 10.0);
+        Ok(())
+    }
+
+    #[test]
+    fn black_to_white_is_longer_than_black_to_grey() -> Result<()> {
+        let black = Srgb::<f64>::new(0.0, 0.0, 0.0)?;
+        let full = SrgbMap::new(&[black, Srgb::new(1.0, 1.0, 1.0)?])?;
+        let half = SrgbMap::new(&[black, Srgb::new(0.5, 0.5, 0.5)?])?;
+
+        let (full_length, half_length) = (full.perceptual_length(64)?, half.perceptual_length(64)?);
+        assert!(full_length > half_length);
+        // A neutral ramp is straight in Lab, so its length is the lightness difference
+        assert!((full_length - 100.0).abs() < 1e-2);
+        assert!(full.perceptual_length(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn kmeans_recovers_two_distinct_colours() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?;
+        let pixels = [red, blue, blue, red, red, blue, red, red];
+
+        let map = RgbMap::from_pixels_kmeans(&pixels, 2, 10)?;
+        // Centroids are ordered from darkest to lightest
+        assert_eq!(map.colours(), [blue, red]);
+        Ok(())
+    }
+
+    #[test]
+    fn kmeans_centroids_are_cluster_means() -> Result<()> {
+        let pixels = [
+            Lab::<f64>::new(20.0, 10.0, 0.0)?,
+            Lab::new(22.0, 12.0, 0.0)?,
+            Lab::new(80.0, -10.0, 5.0)?,
+            Lab::new(84.0, -14.0, 5.0)?,
+        ];
+
+        let map = LabMap::from_pixels_kmeans(&pixels, 2, 10)?;
+        for (centroid, expected) in map.colours().iter().zip([[21.0, 11.0, 0.0], [82.0, -12.0, 5.0]]) {
+            for (value, expected) in centroid.components().iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(LabMap::from_pixels_kmeans(&pixels, 10, 10)?.len(), 4);
+        assert!(matches!(
+            LabMap::from_pixels_kmeans(&pixels, 0, 10),
+            Err(ChromaticError::ColourMap(ColourMapError::EmptyColourMap))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn sample_unchecked_tracks_pushed_colours() -> Result<()> {
+        let mut map = GreyMap::<f64>::new(&[Grey::new(0.0)?, Grey::new(0.5)?])?;
+        map.push(Grey::new(1.0)?);
+
+        assert!((map.sample_unchecked(0.25).grey() - 0.25).abs() < 1e-12);
+        assert!((map.sample_unchecked(1.0).grey() - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn kmeans_maps_out_of_gamut_centroids_by_chroma() -> Result<()> {
+        let vivid = Lab::<f64>::new(50.0, 90.0, -100.0)?;
+        let pixels = [vivid.to_xyz()?, vivid.to_xyz()?];
+
+        // Clamping each RGB channel would darken this centroid to L* ≈ 46.4
+        let centroid = XyzMap::from_pixels_kmeans(&pixels, 1, 10)?.colours()[0].to_lab()?;
+        assert!((centroid.lightness() - vivid.lightness()).abs() < 1e-3);
+        assert!(centroid.in_srgb_gamut());
+        Ok(())
     }
 }
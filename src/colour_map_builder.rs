@@ -0,0 +1,130 @@
+//! ## `ColourMapBuilder` Module
+//!
+//! This module provides the `ColourMapBuilder` struct, for constructing a positioned `ColourMap` one stop at a time.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{colour_map::ColourMap, error::Result, traits::Colour};
+
+/// Builder for a `ColourMap` with explicit stop positions.
+///
+/// Stops are collected as given and validated when the map is built, with the same rules as
+/// [`ColourMap::from_positions`].
+///
+/// ```rust,ignore
+/// let map = ColourMap::builder()
+///     .stop(Rgb::new(0.0, 0.0, 1.0)?, 0.0)
+///     .stop(Rgb::new(1.0, 1.0, 1.0)?, 0.3)
+///     .stop(Rgb::new(1.0, 0.0, 0.0)?, 1.0)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColourMapBuilder<C, T, const N: usize>
+where
+    C: Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// The stops added so far, as (colour, position) pairs.
+    stops: Vec<(C, T)>,
+}
+
+impl<C, T, const N: usize> ColourMapBuilder<C, T, N>
+where
+    C: Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// Create a builder with no stops.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { stops: Vec::new() }
+    }
+
+    /// Add a stop at the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `colour` - The colour at the stop
+    /// * `position` - The position of the stop, must be in range [0, 1] and greater than the previous stop
+    #[must_use]
+    pub fn stop(mut self, colour: C, position: T) -> Self {
+        self.stops.push((colour, position));
+        self
+    }
+
+    /// Build the colour map from the stops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No stops were added
+    /// - Any position is outside [0, 1]
+    /// - Positions are not in ascending order
+    /// - Two stops share a position
+    pub fn build(&self) -> Result<ColourMap<C, T, N>> {
+        ColourMap::from_positions(&self.stops)
+    }
+}
+
+impl<C, T, const N: usize> Default for ColourMapBuilder<C, T, N>
+where
+    C: Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, T, const N: usize> ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N>,
+    T: Float + Send + Sync,
+{
+    /// Start building a colour map with explicit stop positions (see [`ColourMapBuilder`]).
+    #[must_use]
+    pub const fn builder() -> ColourMapBuilder<C, T, N> {
+        ColourMapBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{ChromaticError, ColourMapError},
+        spaces::Rgb,
+    };
+
+    #[test]
+    fn builder_matches_from_positions() -> Result<()> {
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?;
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0)?;
+
+        let map = ColourMap::builder().stop(blue, 0.0).stop(white, 0.3).build()?;
+        let expected = ColourMap::from_positions(&[(blue, 0.0), (white, 0.3)])?;
+        assert_eq!(map.positions(), expected.positions());
+        assert_eq!(map.colours(), expected.colours());
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_order_stops_are_rejected() -> Result<()> {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0)?;
+
+        let result = ColourMapBuilder::new().stop(black, 0.6).stop(black, 0.2).build();
+        assert!(matches!(
+            result,
+            Err(ChromaticError::ColourMap(ColourMapError::NonAscendingPositions {
+                idx1: 0,
+                idx2: 1,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            ColourMapBuilder::<Rgb<f64>, f64, 3>::new().build(),
+            Err(ChromaticError::ColourMap(ColourMapError::EmptyColourMap))
+        ));
+        Ok(())
+    }
+}
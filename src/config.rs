@@ -2,3 +2,6 @@
 
 /// Character used to print the colour in the terminal.
 pub const PRINT_BLOCK: char = '\u{2588}';
+
+/// CIEDE2000 colour difference below which most observers cannot tell two colours apart.
+pub const JUST_NOTICEABLE_DIFFERENCE: f64 = 1.0;
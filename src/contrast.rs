@@ -0,0 +1,71 @@
+//! ## Contrast Module
+//!
+//! This module provides the WCAG 2.1 contrast ratio between two colours.
+
+use num_traits::Float;
+
+use crate::{
+    error::{Result, safe_constant},
+    spaces::Srgb,
+};
+
+/// Calculate the WCAG 2.1 contrast ratio between two colours.
+///
+/// The ratio is `(L1 + 0.05) / (L2 + 0.05)`, where `L1` is the relative luminance of the lighter colour
+/// and `L2` that of the darker, so the result is the same regardless of argument order.
+/// It ranges from 1 for identical luminances to 21 for black on white.
+///
+/// # Arguments
+///
+/// * `a` - The first colour
+/// * `b` - The second colour
+///
+/// # Errors
+///
+/// Returns an error if calculating either relative luminance fails.
+pub fn contrast_ratio<T: Float + Send + Sync>(a: &Srgb<T>, b: &Srgb<T>) -> Result<T> {
+    let offset = safe_constant(0.05)?;
+    let (lum_a, lum_b) = (a.relative_luminance()?, b.relative_luminance()?);
+
+    Ok((lum_a.max(lum_b) + offset) / (lum_a.min(lum_b) + offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Colour;
+
+    #[test]
+    fn black_on_white_is_twenty_one() -> Result<()> {
+        let black = Srgb::<f64>::new(0.0, 0.0, 0.0)?;
+        let white = Srgb::<f64>::new(1.0, 1.0, 1.0)?;
+
+        assert!((contrast_ratio(&black, &white)? - 21.0).abs() < 1e-12);
+        assert!((contrast_ratio(&white, &white)? - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn contrast_ratio_is_symmetric() -> Result<()> {
+        let blue = Srgb::<f64>::from_hex("#1f77b4")?;
+        let yellow = Srgb::<f64>::from_hex("#ffd700")?;
+
+        assert_eq!(
+            contrast_ratio(&blue, &yellow)?.to_bits(),
+            contrast_ratio(&yellow, &blue)?.to_bits()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn relative_luminance_uses_wcag_weights() -> Result<()> {
+        let green = Srgb::<f64>::new(0.0, 1.0, 0.0)?;
+        let grey = Srgb::<f64>::from_hex("#777777")?;
+
+        assert!((green.relative_luminance()? - 0.7152).abs() < 1e-12);
+        // Mid grey #777777 is the classic 4.48:1 against white
+        let white = Srgb::<f64>::new(1.0, 1.0, 1.0)?;
+        assert!((contrast_ratio(&grey, &white)? - 4.48).abs() < 0.01);
+        Ok(())
+    }
+}
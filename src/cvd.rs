@@ -0,0 +1,145 @@
+//! ## Colour Vision Deficiency Module
+//!
+//! This module provides simulation of dichromatic colour vision deficiencies (CVD),
+//! and a check that a palette remains distinguishable under each of them.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{
+    error::{Result, safe_constant},
+    spaces::{Lab, Rgb},
+    traits::Convert,
+};
+
+/// Dichromatic colour vision deficiencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColourVisionDeficiency {
+    /// Absence of long-wavelength (red) cones.
+    Protanopia,
+    /// Absence of medium-wavelength (green) cones.
+    Deuteranopia,
+    /// Absence of short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColourVisionDeficiency {
+    /// All supported deficiencies.
+    pub const ALL: [Self; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// Simulation matrix operating on linear RGB (Machado, Oliveira & Fernandes 2009, severity 1.0).
+    const fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// Simulate how a colour appears to an observer with this deficiency.
+    ///
+    /// # Arguments
+    ///
+    /// * `colour` - The linear RGB colour to simulate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn simulate<T: Float + Send + Sync>(self, colour: &Rgb<T>) -> Result<Rgb<T>> {
+        let input = [colour.red(), colour.green(), colour.blue()];
+
+        let mut output = [T::zero(); 3];
+        for (out, row) in output.iter_mut().zip(self.matrix()) {
+            let mut sum = T::zero();
+            for (&coefficient, &component) in row.iter().zip(&input) {
+                sum = sum + safe_constant::<f64, T>(coefficient)? * component;
+            }
+            // Clamp to [0, 1] range
+            *out = sum.max(T::zero()).min(T::one());
+        }
+
+        Rgb::new(output[0], output[1], output[2])
+    }
+}
+
+/// Check whether a palette remains distinguishable under every simulated colour vision deficiency.
+///
+/// Each colour is simulated under protanopia, deuteranopia and tritanopia, and every pair of
+/// simulated colours must have a CIEDE2000 difference of at least `min_delta_e`.
+///
+/// # Arguments
+///
+/// * `colours` - The palette to check
+/// * `min_delta_e` - The minimum CIEDE2000 difference required between any two colours
+///
+/// # Errors
+///
+/// Returns an error if simulation or Lab conversion fails.
+pub fn is_cvd_safe<T: Float + Send + Sync>(colours: &[Rgb<T>], min_delta_e: T) -> Result<bool> {
+    for deficiency in ColourVisionDeficiency::ALL {
+        let simulated = colours
+            .iter()
+            .map(|colour| deficiency.simulate(colour)?.to_lab())
+            .collect::<Result<Vec<Lab<T>>>>()?;
+
+        for (i, lhs) in simulated.iter().enumerate() {
+            for rhs in &simulated[i + 1..] {
+                if lhs.delta_e2000(rhs)? < min_delta_e {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Colour;
+
+    #[test]
+    fn simulation_preserves_white_and_black() -> Result<()> {
+        for deficiency in ColourVisionDeficiency::ALL {
+            let white = deficiency.simulate(&Rgb::<f64>::new(1.0, 1.0, 1.0)?)?;
+            let black = deficiency.simulate(&Rgb::<f64>::new(0.0, 0.0, 0.0)?)?;
+
+            for component in [white.red(), white.green(), white.blue()] {
+                assert!((component - 1.0).abs() < 1e-5);
+            }
+            for component in [black.red(), black.green(), black.blue()] {
+                assert!(component.abs() < 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn red_green_pair_is_unsafe() -> Result<()> {
+        let palette = [Rgb::<f64>::from_hex("#d62728")?, Rgb::<f64>::from_hex("#2ca02c")?];
+
+        assert!(!is_cvd_safe(&palette, 10.0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn blue_orange_pair_is_safe() -> Result<()> {
+        let palette = [Rgb::<f64>::from_hex("#1f77b4")?, Rgb::<f64>::from_hex("#ff7f0e")?];
+
+        assert!(is_cvd_safe(&palette, 10.0)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//! ## Easing Module
+//!
+//! This module provides easing curves for remapping a progression parameter before sampling,
+//! for non-linear gradient animation.
+
+use num_traits::Float;
+
+use crate::error::{Result, safe_constant, validate_interpolation_factor};
+
+/// Easing curves mapping [0, 1] onto [0, 1], with 0 and 1 as fixed points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Identity: `t`.
+    Linear,
+    /// Quadratic acceleration from rest: `t²`.
+    EaseInQuad,
+    /// Quadratic deceleration to rest: `1 - (1 - t)²`.
+    EaseOutQuad,
+    /// Cubic acceleration then deceleration, symmetric about 0.5.
+    EaseInOutCubic,
+    /// Hermite smoothstep: `3t² - 2t³`.
+    SmoothStep,
+}
+
+impl Easing {
+    /// Apply the easing curve to a parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - Parameter value in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` is outside the range [0, 1], or if constant conversion fails.
+    pub fn apply<T: Float + Send + Sync>(self, t: T) -> Result<T> {
+        validate_interpolation_factor(t)?;
+
+        let two = safe_constant::<f64, T>(2.0)?;
+        let three = safe_constant::<f64, T>(3.0)?;
+        match self {
+            Self::Linear => Ok(t),
+            Self::EaseInQuad => Ok(t * t),
+            Self::EaseOutQuad => Ok(T::one() - (T::one() - t).powi(2)),
+            Self::EaseInOutCubic => {
+                if t < safe_constant(0.5)? {
+                    Ok(safe_constant::<f64, T>(4.0)? * t.powi(3))
+                } else {
+                    Ok(T::one() - (two - two * t).powi(3) / two)
+                }
+            }
+            Self::SmoothStep => Ok(t * t * (three - two * t)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curves_fix_endpoints_and_match_formulas() -> Result<()> {
+        let curves = [
+            Easing::Linear,
+            Easing::EaseInQuad,
+            Easing::EaseOutQuad,
+            Easing::EaseInOutCubic,
+            Easing::SmoothStep,
+        ];
+        for easing in curves {
+            assert!(easing.apply(0.0_f64)?.abs() < 1e-12);
+            assert!((easing.apply(1.0_f64)? - 1.0).abs() < 1e-12);
+        }
+
+        let quarter = curves.map(|easing| easing.apply(0.25_f64).expect("0.25 is in range"));
+        for (value, expected) in quarter.iter().zip([0.25, 0.0625, 0.4375, 0.0625, 0.156_25]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert!(Easing::SmoothStep.apply(1.5_f64).is_err());
+        Ok(())
+    }
+}
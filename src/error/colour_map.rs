@@ -16,6 +16,9 @@ pub enum ColourMapError {
     #[error("Positions are not in ascending order: position {pos1} at index {idx1} >= position {pos2} at index {idx2}")]
     NonAscendingPositions { pos1: f64, idx1: usize, pos2: f64, idx2: usize },
 
+    #[error("Positions at index {idx1} and {idx2} are both {position}, leaving a zero-width segment")]
+    DegenerateSpacing { position: f64, idx1: usize, idx2: usize },
+
     #[error("Sampling position {position} is outside valid range [0, 1]")]
     InvalidSamplingPosition { position: f64 },
 
@@ -1,6 +1,7 @@
 //! Colour parsing specific errors.
 
-use std::num::ParseIntError;
+use alloc::string::String;
+use core::num::ParseIntError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -32,6 +33,9 @@ pub enum ColourParsingError {
     #[error("Missing required component: {0}")]
     MissingComponent(String),
 
+    #[error("Invalid number of components: expected {expected}, got {actual}")]
+    InvalidComponentCount { expected: usize, actual: usize },
+
     #[error("Hex string length {actual} is invalid (expected 1, 2, 3, 4, 6, or 8 characters after #)")]
     InvalidHexLength { actual: usize },
 }
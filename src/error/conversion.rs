@@ -1,5 +1,6 @@
 //! Colour conversion specific errors.
 
+use alloc::string::String;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -1,5 +1,6 @@
 //! Interpolation specific errors.
 
+use alloc::string::String;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -1,7 +1,12 @@
 //! Error handling for the `Chromatic` library.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{any::type_name, fmt::Display};
 use num_traits::{Float, ToPrimitive};
-use std::{any::type_name, fmt::Display};
 use thiserror::Error;
 
 mod colour_map;
@@ -113,9 +118,9 @@ pub enum ChromaticError {
 }
 
 /// Result type alias for the Chromatic library.
-pub type Result<T> = std::result::Result<T, ChromaticError>;
+pub type Result<T> = core::result::Result<T, ChromaticError>;
 
-impl From<ChromaticError> for std::fmt::Error {
+impl From<ChromaticError> for core::fmt::Error {
     fn from(_: ChromaticError) -> Self {
         Self
     }
@@ -152,6 +157,16 @@ pub fn validate_unit_component<T: Float + Send + Sync>(value: T, name: &str) ->
     validate_component_range(value, name, T::zero(), T::one())
 }
 
+/// Clamp a component to the range [min, max], mapping NaN to `min`.
+pub fn clamp_component_range<T: Float + Send + Sync>(value: T, min: T, max: T) -> T {
+    value.max(min).min(max)
+}
+
+/// Clamp a component to the range [0, 1], mapping NaN to 0.
+pub fn clamp_unit_component<T: Float + Send + Sync>(value: T) -> T {
+    clamp_component_range(value, T::zero(), T::one())
+}
+
 /// Validate an interpolation factor is within [0, 1] range.
 pub fn validate_interpolation_factor<T: Float + Send + Sync>(t: T) -> Result<()> {
     if t < T::zero() || t > T::one() {
@@ -163,6 +178,18 @@ pub fn validate_interpolation_factor<T: Float + Send + Sync>(t: T) -> Result<()>
     Ok(())
 }
 
+/// Validate a gamma exponent is positive and finite.
+pub fn validate_gamma<T: Float + Send + Sync>(gamma: T) -> Result<()> {
+    if gamma <= T::zero() || !gamma.is_finite() {
+        return Err(NumericError::InvalidMathOperation(format!(
+            "Gamma must be positive and finite, got {}",
+            gamma.to_f64().unwrap_or(f64::NAN)
+        ))
+        .into());
+    }
+    Ok(())
+}
+
 /// Convert a component to u8 with proper error handling.
 pub fn component_to_u8<T: Float + Send + Sync>(value: T, name: &str, scale_factor: T) -> Result<u8> {
     let scaled = (value * scale_factor).round();
@@ -186,6 +213,29 @@ pub fn u8_to_component<T: Float + Send + Sync>(value: u8, scale_factor: T) -> Re
     Ok(converted / scale_factor)
 }
 
+/// Convert a component to u16 with proper error handling.
+pub fn component_to_u16<T: Float + Send + Sync>(value: T, name: &str, scale_factor: T) -> Result<u16> {
+    let scaled = (value * scale_factor).round();
+    scaled.to_u16().ok_or_else(|| {
+        NumericError::TypeConversionFailed {
+            from: type_name::<T>().to_string(),
+            to: "u16".to_string(),
+            reason: format!(
+                "{} value {} is outside u16 range [0, 65535]",
+                name,
+                scaled.to_f64().unwrap_or(f64::NAN)
+            ),
+        }
+        .into()
+    })
+}
+
+/// Convert a u16 component to the target float type with proper scaling.
+pub fn u16_to_component<T: Float + Send + Sync>(value: u16, scale_factor: T) -> Result<T> {
+    let converted = safe_constant::<u16, T>(value)?;
+    Ok(converted / scale_factor)
+}
+
 /// Parse a hex string component (1 or 2 characters) to u8.
 pub fn parse_hex_component(hex: &str, component_name: &str) -> Result<u8> {
     u8::from_str_radix(hex, 16).map_err(|source| {
@@ -197,6 +247,56 @@ pub fn parse_hex_component(hex: &str, component_name: &str) -> Result<u8> {
     })
 }
 
+/// Split a CSS functional notation string (e.g. `rgb(255, 0, 0)`) into its three arguments.
+///
+/// The function name is matched case-insensitively, and arguments may be separated by commas or whitespace.
+pub fn parse_css_function<'a>(css: &'a str, name: &str) -> Result<[&'a str; 3]> {
+    let css = css.trim();
+    let arguments = css
+        .get(..name.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+        .and_then(|_| css[name.len()..].trim_start().strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(|| ColourParsingError::InvalidFormat(format!("Expected '{name}(...)', got '{css}'")))?;
+
+    let arguments: Vec<&str> = if arguments.contains(',') {
+        arguments.split(',').map(str::trim).collect()
+    } else {
+        arguments.split_whitespace().collect()
+    };
+
+    let actual = arguments.len();
+    arguments
+        .try_into()
+        .map_err(|_| ColourParsingError::InvalidComponentCount { expected: 3, actual }.into())
+}
+
+/// Parse a CSS number, rejecting anything that is not a finite decimal value.
+pub fn parse_css_number(value: &str, component_name: &str) -> Result<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| ColourParsingError::InvalidFormat(format!("Invalid {component_name} value '{value}'")).into())
+}
+
+/// Parse a CSS number in the range [0, max] and scale it to [0, 1].
+pub fn parse_css_fraction<T: Float + Send + Sync>(value: &str, component_name: &str, max: f64) -> Result<T> {
+    let number = parse_css_number(value, component_name)?;
+    if !(0.0..=max).contains(&number) {
+        return Err(ColourParsingError::ComponentOutOfRange {
+            component: component_name.to_string(),
+            value: number,
+            min: 0.0,
+            max,
+        }
+        .into());
+    }
+
+    safe_constant(number / max)
+}
+
 /// Normalize hue to [0, 360) range with overflow protection.
 pub fn normalize_hue<T: Float + Send + Sync>(mut hue: T) -> Result<T> {
     const MAX_ITERATIONS: usize = 1000;
@@ -1,5 +1,6 @@
 //! Numeric computation specific errors.
 
+use alloc::string::String;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
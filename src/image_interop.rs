@@ -0,0 +1,89 @@
+//! Conversions to and from the `image` crate's pixel and buffer types.
+//!
+//! Pixels are sRGB-encoded RGBA8, so they correspond to `SrgbAlpha` via its byte representation.
+//! Both directions are fallible, as byte conversion may fail for custom float types.
+
+use ::image::{Rgba, RgbaImage};
+use alloc::{format, string::ToString};
+use core::any::type_name;
+use num_traits::Float;
+
+use crate::{
+    colour_map::ColourMap,
+    error::{ChromaticError, NumericError, Result},
+    spaces::SrgbAlpha,
+    traits::{Colour, Convert},
+};
+
+impl<T: Float + Send + Sync> TryFrom<Rgba<u8>> for SrgbAlpha<T> {
+    type Error = ChromaticError;
+
+    fn try_from(pixel: Rgba<u8>) -> Result<Self> {
+        Self::from_bytes(pixel.0)
+    }
+}
+
+impl<T: Float + Send + Sync> TryFrom<SrgbAlpha<T>> for Rgba<u8> {
+    type Error = ChromaticError;
+
+    fn try_from(colour: SrgbAlpha<T>) -> Result<Self> {
+        Ok(Self(colour.to_bytes()?))
+    }
+}
+
+impl<C, T, const N: usize> ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N> + Convert<T>,
+    T: Float + Send + Sync,
+{
+    /// Render the map as an `image::RgbaImage`, with each column sampled as in [`ColourMap::to_rgba_strip`].
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The image width in pixels, must be at least 1
+    /// * `height` - The image height in pixels
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `width` is 0, or if sampling or conversion to sRGB fails.
+    pub fn to_image(&self, width: u32, height: u32) -> Result<RgbaImage> {
+        let to_usize = |value: u32| {
+            usize::try_from(value).map_err(|_| NumericError::TypeConversionFailed {
+                from: type_name::<u32>().to_string(),
+                to: type_name::<usize>().to_string(),
+                reason: format!("Image dimension {value} does not fit in usize"),
+            })
+        };
+
+        let pixels = self.to_rgba_strip(to_usize(width)?, to_usize(height)?)?;
+        RgbaImage::from_raw(width, height, pixels).ok_or_else(|| {
+            NumericError::InvalidMathOperation(format!("Pixel buffer does not match a {width}x{height} image")).into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{colour_map::RgbMap, spaces::Rgb};
+
+    #[test]
+    fn to_image_endpoints_match_the_map() -> Result<()> {
+        let map = RgbMap::<f64>::new(&[Rgb::new(1.0, 0.0, 0.0)?, Rgb::new(0.0, 0.0, 1.0)?])?;
+
+        let image = map.to_image(16, 1)?;
+        assert_eq!(image.dimensions(), (16, 1));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(15, 0).0, [0, 0, 255, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn pixels_round_trip_through_srgb_alpha() -> Result<()> {
+        let pixel = Rgba([12, 34, 56, 78]);
+
+        let colour = SrgbAlpha::<f64>::try_from(pixel)?;
+        assert_eq!(Rgba::try_from(colour)?, pixel);
+        Ok(())
+    }
+}
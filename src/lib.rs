@@ -2,6 +2,7 @@
 //!
 //! `Chromatic` is a simple library for building and sampling colour maps.
 
+#![no_std]
 #![deny(absolute_paths_not_starting_with_crate)]
 #![deny(ambiguous_negative_literals)]
 #![deny(dead_code)]
@@ -75,21 +76,64 @@
 )]
 #![allow(clippy::module_inception, reason = "Modules publicly re-export types of the same name.")]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(all(test, not(feature = "serde")))]
+use serde_json as _;
+
+mod baked_map;
 mod colour_map;
+mod colour_map_builder;
 mod config;
+mod contrast;
+mod cvd;
+mod easing;
 mod error;
+#[cfg(feature = "image")]
+mod image_interop;
+mod mix;
+mod named;
+mod palette;
+#[cfg(feature = "palette-interop")]
+mod palette_interop;
+#[cfg(feature = "rand")]
+mod random;
+mod scheme;
+#[cfg(feature = "serde")]
+mod serde_support;
 mod spaces;
+mod temperature;
 mod traits;
+mod transfer;
 
 pub mod prelude {
     //! Prelude of commonly used types and traits in the `chromatic` crate.
+    #[cfg(feature = "spectral")]
+    pub use crate::spaces::Spectral;
     pub use crate::{
+        baked_map::BakedMap,
+        colour_map,
         colour_map::{
-            ColourMap, GreyAlphaMap, GreyMap, HslAlphaMap, HslMap, HsvAlphaMap, HsvMap, LabAlphaMap, LabMap, RgbAlphaMap,
-            RgbMap, SrgbAlphaMap, SrgbMap, XyzAlphaMap, XyzMap,
+            AdobeRgbMap, CmykMap, ColourMap, DisplayP3Map, GreyAlphaMap, GreyMap, HpluvMap, HslAlphaMap, HslMap, HsluvMap,
+            HsvAlphaMap, HsvMap, LabAlphaMap, LabMap, LchuvMap, LuvMap, OklabMap, RgbAlphaMap, RgbMap, SrgbAlphaMap, SrgbMap,
+            XyYMap, XyzAlphaMap, XyzMap,
         },
+        colour_map_builder::ColourMapBuilder,
+        contrast::contrast_ratio,
+        cvd::{ColourVisionDeficiency, is_cvd_safe},
+        easing::Easing,
         error::ChromaticError,
-        spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-        traits::{Colour, Convert},
+        mix::{MixSpace, color_mix, color_mix_with, mix},
+        palette::{component_max, component_mean, component_min, merge_palettes},
+        scheme::SchemeKind,
+        spaces::{
+            AdobeRgb, Cmyk, DisplayP3, GammaPrecision, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab,
+            LabAlpha, Lchuv, LinearRgb, Luv, Oklab, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+        },
+        temperature::Temperature,
+        traits::{Alpha, ApproxEq, Colour, Convert, Gamut},
+        transfer::TransferFunction,
     };
 }
@@ -0,0 +1,243 @@
+//! ## Mix Module
+//!
+//! This module provides colour mixing with the semantics of the CSS Color 5 `color-mix()` function,
+//! and weighted averages of any number of colours within a single space.
+
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+use num_traits::Float;
+
+use crate::{
+    error::{InterpolationError, Result, safe_constant, validate_component_range},
+    spaces::{Rgb, RgbAlpha},
+    traits::{Colour, Convert},
+};
+
+/// Colour spaces that `color_mix` can interpolate in.
+///
+/// Cylindrical spaces interpolate hue along the shortest path around the hue circle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MixSpace {
+    /// Gamma-encoded sRGB, as CSS `srgb`.
+    Srgb,
+    /// Linear RGB, as CSS `srgb-linear`.
+    SrgbLinear,
+    /// CIE L*a*b*, as CSS `lab`.
+    Lab,
+    /// CIE XYZ, as CSS `xyz`.
+    Xyz,
+    /// Hue, saturation and lightness, as CSS `hsl`.
+    Hsl,
+    /// Hue, saturation and value.
+    Hsv,
+    /// `HSLuv`.
+    Hsluv,
+    /// `HPLuv`.
+    Hpluv,
+}
+
+/// Mix two colours like CSS `color-mix(in <space>, a <a_pct>%, b)`.
+///
+/// The percentage of `b` is the remainder, `100 - a_pct`.
+///
+/// # Arguments
+///
+/// * `a` - The first colour
+/// * `b` - The second colour
+/// * `a_pct` - The percentage of `a` in the mix, must be in range [0, 100]
+/// * `space` - The colour space to interpolate in
+///
+/// # Errors
+///
+/// Returns an error if `a_pct` is outside [0, 100], or if conversion or interpolation fails.
+pub fn color_mix<T, C, const N: usize>(a: &C, b: &C, a_pct: T, space: MixSpace) -> Result<C>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N> + Convert<T>,
+{
+    let hundred = safe_constant(100.0)?;
+    validate_component_range(a_pct, "a_pct", T::zero(), hundred)?;
+    color_mix_with(a, a_pct, b, hundred - a_pct, space)
+}
+
+/// Mix two colours like CSS `color-mix(in <space>, a <a_pct>%, b <b_pct>%)`.
+///
+/// Percentages that do not sum to 100 are normalised before interpolating.
+/// If they sum to less than 100, the alpha of the result is also scaled by the sum.
+/// Colour and alpha are interpolated separately, so alpha is not premultiplied.
+///
+/// # Arguments
+///
+/// * `a` - The first colour
+/// * `a_pct` - The percentage of `a` in the mix, must be in range [0, 100]
+/// * `b` - The second colour
+/// * `b_pct` - The percentage of `b` in the mix, must be in range [0, 100]
+/// * `space` - The colour space to interpolate in
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Either percentage is outside [0, 100]
+/// - Both percentages are zero
+/// - Conversion or interpolation fails
+pub fn color_mix_with<T, C, const N: usize>(a: &C, a_pct: T, b: &C, b_pct: T, space: MixSpace) -> Result<C>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N> + Convert<T>,
+{
+    let hundred = safe_constant(100.0)?;
+    validate_component_range(a_pct, "a_pct", T::zero(), hundred)?;
+    validate_component_range(b_pct, "b_pct", T::zero(), hundred)?;
+
+    let total = a_pct + b_pct;
+    if total <= T::zero() {
+        return Err(InterpolationError::InvalidWeightSum.into());
+    }
+    let t = b_pct / total;
+
+    let rgb = match space {
+        MixSpace::Srgb => lerp_in(&a.to_srgb()?, &b.to_srgb()?, t)?,
+        MixSpace::SrgbLinear => lerp_in(&a.to_rgb()?, &b.to_rgb()?, t)?,
+        MixSpace::Lab => lerp_in(&a.to_lab()?, &b.to_lab()?, t)?,
+        MixSpace::Xyz => lerp_in(&a.to_xyz()?, &b.to_xyz()?, t)?,
+        MixSpace::Hsl => lerp_in(&a.to_hsl()?, &b.to_hsl()?, t)?,
+        MixSpace::Hsv => lerp_in(&a.to_hsv()?, &b.to_hsv()?, t)?,
+        MixSpace::Hsluv => lerp_in(&a.to_hsluv()?, &b.to_hsluv()?, t)?,
+        MixSpace::Hpluv => lerp_in(&a.to_hpluv()?, &b.to_hpluv()?, t)?,
+    };
+
+    let (alpha_a, alpha_b) = (a.to_rgb_alpha()?.alpha(), b.to_rgb_alpha()?.alpha());
+    let alpha = (alpha_a * (T::one() - t) + alpha_b * t) * (total / hundred).min(T::one());
+
+    C::from_rgb_alpha(&RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), alpha)?)
+}
+
+/// Mix any number of colours by weight, averaging in the colour's own component space.
+///
+/// Weights are normalised before averaging, so they need not sum to one.
+/// Lab-backed colours therefore mix perceptually. This is equivalent to `Colour::mix`.
+///
+/// # Arguments
+///
+/// * `colours` - The colours to mix, each paired with its weight
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The colour list is empty
+/// - Any weight is negative
+/// - The sum of weights is zero or invalid
+/// - Interpolation calculations fail
+pub fn mix<C, T, const N: usize>(colours: &[(C, T)]) -> Result<C>
+where
+    T: Float + Send + Sync + AddAssign,
+    C: Colour<T, N> + Clone,
+{
+    let (colours, weights): (Vec<C>, Vec<T>) = colours.iter().cloned().unzip();
+    C::mix(&colours, &weights)
+}
+
+/// Interpolate between two colours in their own colour space, returning the result in linear RGB.
+fn lerp_in<T, S>(lhs: &S, rhs: &S, t: T) -> Result<Rgb<T>>
+where
+    T: Float + Send + Sync,
+    S: Colour<T, 3> + Convert<T>,
+{
+    S::lerp(lhs, rhs, t)?.to_rgb()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ChromaticError;
+    use crate::spaces::{GammaPrecision, Hsl, Srgb, SrgbAlpha};
+
+    #[test]
+    fn mix_equal_weights_matches_lerp() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?;
+
+        let mixed = mix(&[(red, 0.5), (blue, 0.5)])?;
+        let expected = Rgb::lerp(&red, &blue, 0.5)?;
+        for (lhs, rhs) in mixed.components().iter().zip(expected.components()) {
+            assert!((lhs - rhs).abs() < 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mix_normalises_weights() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?;
+
+        let mixed = mix(&[(red, 3.0), (blue, 1.0)])?;
+        assert!((mixed.red() - 0.75).abs() < 1e-12);
+        assert!((mixed.blue() - 0.25).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn mix_rejects_empty_input_and_negative_weights() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+
+        assert!(matches!(mix::<Rgb<f64>, f64, 3>(&[]), Err(ChromaticError::Interpolation(_))));
+        assert!(matches!(
+            mix(&[(red, -1.0), (red, 2.0)]),
+            Err(ChromaticError::Interpolation(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn color_mix_black_and_white_in_srgb_is_mid_grey() -> Result<()> {
+        let black = Srgb::<f64>::from_hex("#000000")?;
+        let white = Srgb::<f64>::from_hex("#ffffff")?;
+
+        let mixed = color_mix(&black, &white, 50.0, MixSpace::Srgb)?;
+        for component in mixed.components() {
+            assert!((component - 0.5).abs() < 1e-2);
+        }
+        if GammaPrecision::DEFAULT == GammaPrecision::Exact {
+            assert_eq!(mixed.to_hex()?, "#808080");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn color_mix_in_hsl_takes_shortest_hue_path() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?;
+
+        // Red sits at 0° and blue at 240°, so 70% of the way via the short arc is 276°
+        let mixed = color_mix(&red, &blue, 30.0, MixSpace::Hsl)?;
+        let expected = Hsl::<f64>::new(276.0, 1.0, 0.5)?.to_rgb()?;
+        // The hop to HSL goes through the sRGB curve, which is only accurate to about 1e-4 under `fast-gamma`
+        let tolerance = if GammaPrecision::DEFAULT == GammaPrecision::Exact {
+            1e-9
+        } else {
+            2e-4
+        };
+        for (lhs, rhs) in mixed.components().iter().zip(expected.components()) {
+            assert!((lhs - rhs).abs() < tolerance);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn color_mix_with_normalises_percentages_and_scales_alpha() -> Result<()> {
+        let red = SrgbAlpha::<f64>::new(1.0, 0.0, 0.0, 1.0)?;
+        let blue = SrgbAlpha::<f64>::new(0.0, 0.0, 1.0, 1.0)?;
+
+        let partial = color_mix_with(&red, 20.0, &blue, 20.0, MixSpace::Srgb)?;
+        let full = color_mix(&red, &blue, 50.0, MixSpace::Srgb)?;
+        for (lhs, rhs) in partial.components().iter().zip(full.components()).take(3) {
+            assert!((lhs - rhs).abs() < 1e-9);
+        }
+        assert!((partial.alpha() - 0.4).abs() < 1e-9);
+        assert!((full.alpha() - 1.0).abs() < 1e-9);
+
+        assert!(color_mix(&red, &blue, 120.0, MixSpace::Srgb).is_err());
+        assert!(color_mix_with(&red, 0.0, &blue, 0.0, MixSpace::Srgb).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+//! ## Palette Module
+//!
+//! This module provides utilities for working with palettes of discrete colours.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{
+    error::{InterpolationError, Result, safe_constant},
+    spaces::Lab,
+    traits::Colour,
+};
+
+/// Merge two palettes, collapsing near-duplicate colours into their centroid.
+///
+/// Colours are taken from `a` followed by `b`. Each colour joins the first kept cluster whose
+/// centroid is within a CIEDE2000 difference of `min_delta_e`, otherwise it starts a new cluster.
+/// The Lab mean of each cluster is returned, in the order the clusters were created.
+///
+/// # Arguments
+///
+/// * `a` - The first palette
+/// * `b` - The second palette
+/// * `min_delta_e` - The minimum CIEDE2000 difference between distinct colours
+///
+/// # Errors
+///
+/// Returns an error if the colour difference or centroid calculation fails.
+pub fn merge_palettes<T: Float + Send + Sync>(a: &[Lab<T>], b: &[Lab<T>], min_delta_e: T) -> Result<Vec<Lab<T>>> {
+    // Each cluster holds its current centroid, the component sums and the number of members
+    let mut clusters: Vec<(Lab<T>, [T; 3], usize)> = Vec::new();
+
+    for colour in a.iter().chain(b) {
+        let mut merged = false;
+
+        for (centroid, sums, count) in &mut clusters {
+            if centroid.delta_e2000(colour)? < min_delta_e {
+                sums[0] = sums[0] + colour.lightness();
+                sums[1] = sums[1] + colour.a_star();
+                sums[2] = sums[2] + colour.b_star();
+                *count += 1;
+
+                let n = safe_constant::<usize, T>(*count)?;
+                *centroid = Lab::new(sums[0] / n, sums[1] / n, sums[2] / n)?;
+                merged = true;
+                break;
+            }
+        }
+
+        if !merged {
+            clusters.push((*colour, [colour.lightness(), colour.a_star(), colour.b_star()], 1));
+        }
+    }
+
+    Ok(clusters.into_iter().map(|(centroid, _, _)| centroid).collect())
+}
+
+/// Find the smallest value of each component across a palette.
+///
+/// Components are compared in their native units, so hue components are not treated as circular.
+///
+/// # Arguments
+///
+/// * `colours` - The palette to inspect, must not be empty
+///
+/// # Errors
+///
+/// Returns an error if the palette is empty.
+pub fn component_min<T, C, const N: usize>(colours: &[C]) -> Result<[T; N]>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N>,
+{
+    fold_components(colours, T::min)
+}
+
+/// Find the largest value of each component across a palette.
+///
+/// Components are compared in their native units, so hue components are not treated as circular.
+///
+/// # Arguments
+///
+/// * `colours` - The palette to inspect, must not be empty
+///
+/// # Errors
+///
+/// Returns an error if the palette is empty.
+pub fn component_max<T, C, const N: usize>(colours: &[C]) -> Result<[T; N]>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N>,
+{
+    fold_components(colours, T::max)
+}
+
+/// Calculate the arithmetic mean of each component across a palette.
+///
+/// Components are averaged in their native units, so hue components are not treated as circular.
+///
+/// # Arguments
+///
+/// * `colours` - The palette to inspect, must not be empty
+///
+/// # Errors
+///
+/// Returns an error if the palette is empty or if constant conversion fails.
+pub fn component_mean<T, C, const N: usize>(colours: &[C]) -> Result<[T; N]>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N>,
+{
+    let sums = fold_components(colours, |sum, value| sum + value)?;
+    let count = safe_constant::<usize, T>(colours.len())?;
+    Ok(sums.map(|sum| sum / count))
+}
+
+/// Combine the components of every colour in a palette channel by channel, starting from the first colour.
+fn fold_components<T, C, const N: usize>(colours: &[C], combine: impl Fn(T, T) -> T) -> Result<[T; N]>
+where
+    T: Float + Send + Sync,
+    C: Colour<T, N>,
+{
+    let (first, rest) = colours.split_first().ok_or(InterpolationError::EmptyColourList)?;
+
+    let mut result = first.components();
+    for colour in rest {
+        for (acc, value) in result.iter_mut().zip(colour.components()) {
+            *acc = combine(*acc, value);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::Rgb;
+
+    #[test]
+    fn merge_palettes_collapses_near_identical_red() -> Result<()> {
+        let a = [Lab::<f64>::new(53.0, 80.0, 67.0)?, Lab::new(30.0, 20.0, -60.0)?];
+        let b = [Lab::<f64>::new(53.4, 80.5, 66.6)?, Lab::new(90.0, -5.0, 80.0)?];
+
+        let merged = merge_palettes(&a, &b, 2.0)?;
+        assert_eq!(merged.len(), 3);
+        assert!((merged[0].lightness() - 53.2).abs() < 1e-9);
+        assert!((merged[0].a_star() - 80.25).abs() < 1e-9);
+        assert!((merged[0].b_star() - 66.8).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn component_statistics_over_small_palette() -> Result<()> {
+        let colours = [
+            Rgb::<f64>::new(0.1, 0.8, 0.3)?,
+            Rgb::new(0.6, 0.2, 0.9)?,
+            Rgb::new(0.4, 0.5, 0.0)?,
+        ];
+
+        assert_eq!(component_max(&colours)?.map(f64::to_bits), [0.6, 0.8, 0.9].map(f64::to_bits));
+        assert_eq!(component_min(&colours)?.map(f64::to_bits), [0.1, 0.2, 0.0].map(f64::to_bits));
+        for (mean, expected) in component_mean(&colours)?.iter().zip([1.1 / 3.0, 0.5, 0.4]) {
+            assert!((mean - expected).abs() < 1e-12);
+        }
+        assert!(component_max::<f64, Rgb<f64>, 3>(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn clamp_each_moves_colour_into_box() -> Result<()> {
+        let colour = Rgb::<f64>::new(0.05, 0.5, 0.95)?;
+
+        let clamped = colour.clamp_each([0.1, 0.1, 0.1], [0.9, 0.4, 0.9])?;
+        assert_eq!(clamped.components().map(f64::to_bits), [0.1, 0.4, 0.9].map(f64::to_bits));
+        Ok(())
+    }
+}
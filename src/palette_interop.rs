@@ -0,0 +1,135 @@
+//! Conversions to and from the `palette` crate's colour types.
+//!
+//! Components are copied directly between equivalent types, so no precision is lost:
+//! - `Rgb` (linear) and `palette::LinSrgb`.
+//! - `Srgb` (gamma-encoded) and `palette::Srgb`.
+//! - `Lab` and `palette::Lab` (D65 white point).
+//! - `Hsl` and `palette::Hsl`, both built over sRGB-encoded RGB.
+//!
+//! Converting from `palette` is fallible, as `palette` does not restrict component ranges.
+
+use ::palette::RgbHue;
+
+use crate::{
+    error::ChromaticError,
+    spaces::{Hsl, Lab, Rgb, Srgb},
+};
+
+/// Macro to implement conversions between this crate's colour types and `palette`'s for a float type.
+macro_rules! impl_palette_interop {
+    ($float:ty) => {
+        impl From<Rgb<$float>> for ::palette::LinSrgb<$float> {
+            fn from(colour: Rgb<$float>) -> Self {
+                Self::new(colour.red(), colour.green(), colour.blue())
+            }
+        }
+
+        impl TryFrom<::palette::LinSrgb<$float>> for Rgb<$float> {
+            type Error = ChromaticError;
+
+            fn try_from(colour: ::palette::LinSrgb<$float>) -> Result<Self, Self::Error> {
+                Self::new(colour.red, colour.green, colour.blue)
+            }
+        }
+
+        impl From<Srgb<$float>> for ::palette::Srgb<$float> {
+            fn from(colour: Srgb<$float>) -> Self {
+                Self::new(colour.red(), colour.green(), colour.blue())
+            }
+        }
+
+        impl TryFrom<::palette::Srgb<$float>> for Srgb<$float> {
+            type Error = ChromaticError;
+
+            fn try_from(colour: ::palette::Srgb<$float>) -> Result<Self, Self::Error> {
+                Self::new(colour.red, colour.green, colour.blue)
+            }
+        }
+
+        impl From<Lab<$float>> for ::palette::Lab<::palette::white_point::D65, $float> {
+            fn from(colour: Lab<$float>) -> Self {
+                Self::new(colour.lightness(), colour.a_star(), colour.b_star())
+            }
+        }
+
+        impl TryFrom<::palette::Lab<::palette::white_point::D65, $float>> for Lab<$float> {
+            type Error = ChromaticError;
+
+            fn try_from(colour: ::palette::Lab<::palette::white_point::D65, $float>) -> Result<Self, Self::Error> {
+                Self::new(colour.l, colour.a, colour.b)
+            }
+        }
+
+        impl From<Hsl<$float>> for ::palette::Hsl<::palette::encoding::Srgb, $float> {
+            fn from(colour: Hsl<$float>) -> Self {
+                Self::new(
+                    RgbHue::from_degrees(colour.hue()),
+                    colour.saturation(),
+                    colour.lightness(),
+                )
+            }
+        }
+
+        impl TryFrom<::palette::Hsl<::palette::encoding::Srgb, $float>> for Hsl<$float> {
+            type Error = ChromaticError;
+
+            fn try_from(colour: ::palette::Hsl<::palette::encoding::Srgb, $float>) -> Result<Self, Self::Error> {
+                Self::new(colour.hue.into_positive_degrees(), colour.saturation, colour.lightness)
+            }
+        }
+    };
+}
+
+impl_palette_interop!(f32);
+impl_palette_interop!(f64);
+
+#[cfg(test)]
+mod tests {
+    use ::palette::FromColor;
+
+    use super::*;
+    use crate::{
+        error::Result,
+        traits::{Colour, Convert},
+    };
+
+    #[test]
+    fn srgb_round_trips_through_palette_at_byte_level() -> Result<()> {
+        for bytes in [[0, 0, 0], [255, 128, 0], [18, 52, 86], [255, 255, 255]] {
+            let colour = Srgb::<f64>::from_bytes(bytes)?;
+
+            let converted: ::palette::Srgb<f64> = colour.into();
+            assert_eq!(converted.into_format::<u8>().into_components(), bytes.into());
+            assert_eq!(Srgb::try_from(converted)?.to_bytes()?, bytes);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn linear_rgb_matches_palette_encoding() -> Result<()> {
+        let colour = Rgb::<f64>::new(0.2, 0.5, 0.9)?;
+
+        let encoded = ::palette::Srgb::<f64>::from_linear(::palette::LinSrgb::from(colour));
+        let expected = colour.to_srgb()?;
+        assert!((encoded.red - expected.red()).abs() < 1e-4);
+        assert!((encoded.green - expected.green()).abs() < 1e-4);
+        assert!((encoded.blue - expected.blue()).abs() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn hsl_matches_palette_hsl() -> Result<()> {
+        let colour = Srgb::<f64>::from_hex("#800000")?.to_hsl()?;
+
+        let converted: ::palette::Hsl<::palette::encoding::Srgb, f64> = colour.into();
+        let encoded = ::palette::Srgb::<f64>::from_color(converted);
+        assert!((encoded.red - 128.0 / 255.0).abs() < 1e-12);
+        assert!(encoded.green.abs() < 1e-12 && encoded.blue.abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range_palette_colours_are_rejected() {
+        assert!(Rgb::<f32>::try_from(::palette::LinSrgb::new(1.5, 0.0, 0.0)).is_err());
+    }
+}
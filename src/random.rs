@@ -0,0 +1,142 @@
+//! Random colour generation driven by a caller-provided random number generator.
+//!
+//! Nothing here owns or seeds a generator, so results are reproducible whenever the caller seeds their own.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+use rand::Rng;
+
+use crate::{
+    colour_map::ColourMap,
+    error::{Result, safe_constant},
+    spaces::{Hsl, Rgb},
+    traits::{Colour, Convert},
+};
+
+/// Saturation used by `Hsl::random_vivid`.
+const VIVID_SATURATION: f64 = 1.0;
+
+/// Lightness used by `Hsl::random_vivid`.
+const VIVID_LIGHTNESS: f64 = 0.5;
+
+/// Draw a value uniformly from [0, 1).
+fn random_unit<T: Float + Send + Sync, R: Rng + ?Sized>(rng: &mut R) -> Result<T> {
+    safe_constant(rng.random::<f64>())
+}
+
+impl<T: Float + Send + Sync> Rgb<T> {
+    /// Create a random linear RGB colour, with each component drawn uniformly from [0, 1).
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw components from
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a drawn value cannot be converted to type `T`.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Result<Self> {
+        Self::new(random_unit(rng)?, random_unit(rng)?, random_unit(rng)?)
+    }
+}
+
+impl<T: Float + Send + Sync> Hsl<T> {
+    /// Create a random fully saturated colour at mid lightness, with the hue drawn uniformly from [0, 360).
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw the hue from
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a drawn value cannot be converted to type `T`.
+    pub fn random_vivid<R: Rng + ?Sized>(rng: &mut R) -> Result<Self> {
+        let hue = random_unit::<T, R>(rng)? * safe_constant(360.0)?;
+        Self::new(hue, safe_constant(VIVID_SATURATION)?, safe_constant(VIVID_LIGHTNESS)?)
+    }
+}
+
+impl<C, T, const N: usize> ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N> + Convert<T>,
+    T: Float + Send + Sync,
+{
+    /// Create a colour map from uniformly spaced random colours, each drawn as in [`Rgb::random`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw colours from
+    /// * `count` - The number of colours in the map, must be at least 1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `count` is 0, or if generating or converting a colour fails.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, count: usize) -> Result<Self> {
+        let colours = (0..count)
+            .map(|_| C::from_rgb_alpha(&Rgb::random(rng)?.to_rgb_alpha()?))
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(&colours)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    use crate::error::{ChromaticError, ColourMapError};
+
+    /// Minimal seeded `SplitMix64` generator, so the tests do not depend on `rand`'s optional generators.
+    #[derive(Debug, Clone, Copy)]
+    struct SplitMix64(u64);
+
+    impl RngCore for SplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for chunk in dst.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_colours() -> Result<()> {
+        let (mut first, mut second) = (SplitMix64(42), SplitMix64(42));
+
+        let lhs = Rgb::<f64>::random(&mut first)?;
+        let rhs = Rgb::<f64>::random(&mut second)?;
+        assert_eq!(lhs.components().map(f64::to_bits), rhs.components().map(f64::to_bits));
+        assert!(lhs.components().iter().all(|component| (0.0..1.0).contains(component)));
+
+        let lhs = Hsl::<f64>::random_vivid(&mut first)?;
+        let rhs = Hsl::<f64>::random_vivid(&mut second)?;
+        assert_eq!(lhs.hue().to_bits(), rhs.hue().to_bits());
+        assert!((lhs.saturation() - 1.0).abs() < 1e-12 && (lhs.lightness() - 0.5).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn random_map_is_deterministic_and_sized() -> Result<()> {
+        let lhs = ColourMap::<Rgb<f64>, f64, 3>::random(&mut SplitMix64(7), 5)?;
+        let rhs = ColourMap::<Rgb<f64>, f64, 3>::random(&mut SplitMix64(7), 5)?;
+
+        assert_eq!(lhs.len(), 5);
+        assert_eq!(lhs.colours(), rhs.colours());
+        assert!(matches!(
+            ColourMap::<Rgb<f64>, f64, 3>::random(&mut SplitMix64(7), 0),
+            Err(ChromaticError::ColourMap(ColourMapError::EmptyColourMap))
+        ));
+        Ok(())
+    }
+}
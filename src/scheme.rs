@@ -0,0 +1,41 @@
+//! ## Colour Scheme Module
+//!
+//! This module provides the kinds of colour scheme that can be generated from a single seed colour.
+//! Schemes are built in `Hsluv`, so hue rotations keep the perceived lightness of the seed.
+
+/// Lowest `Hsluv` lightness given to colours whose lightness is varied from the seed.
+pub const MIN_SCHEME_LIGHTNESS: f64 = 0.1;
+
+/// Highest `Hsluv` lightness given to colours whose lightness is varied from the seed.
+pub const MAX_SCHEME_LIGHTNESS: f64 = 0.95;
+
+/// Kinds of colour scheme generated around a seed colour.
+///
+/// Every scheme starts with the seed itself. Hue angles are measured in `Hsluv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemeKind {
+    /// The seed followed by four shades of its hue, from darker to lighter.
+    Monochromatic,
+    /// Five neighbouring hues, 30° apart.
+    Analogous,
+    /// Three shades of the seed and two of its complement, 180° away.
+    Complementary,
+    /// Three hues, 120° apart.
+    Triadic,
+    /// Four hues, 90° apart.
+    Tetradic,
+}
+
+impl SchemeKind {
+    /// Hue offset in degrees and lightness offset of each colour in the scheme, relative to the seed.
+    #[must_use]
+    pub const fn offsets(self) -> &'static [(f64, f64)] {
+        match self {
+            Self::Monochromatic => &[(0.0, 0.0), (0.0, -0.3), (0.0, -0.15), (0.0, 0.15), (0.0, 0.3)],
+            Self::Analogous => &[(0.0, 0.0), (-60.0, 0.0), (-30.0, 0.0), (30.0, 0.0), (60.0, 0.0)],
+            Self::Complementary => &[(0.0, 0.0), (0.0, -0.15), (0.0, 0.15), (180.0, 0.0), (180.0, 0.15)],
+            Self::Triadic => &[(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)],
+            Self::Tetradic => &[(0.0, 0.0), (90.0, 0.0), (180.0, 0.0), (270.0, 0.0)],
+        }
+    }
+}
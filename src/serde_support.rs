@@ -0,0 +1,212 @@
+//! `serde` support for the colour types.
+//!
+//! Colours serialise to a struct with one named field per component, with alpha variants adding an
+//! `alpha` field (e.g. `{"red":0.5,"green":0.2,"blue":0.1,"alpha":1.0}`).
+//! Deserialisation runs the same validation as each type's `new` constructor, so out-of-range
+//! components are reported as a deserialisation error.
+//!
+//! Colour maps serialise to a struct holding their colours (e.g. `{"colours":[...]}`), with a `positions`
+//! list added for maps built from explicit positions. Deserialisation rejects an empty list of colours, and
+//! validates any positions as `ColourMap::from_positions` does.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::SerializeStruct as _};
+
+use crate::{
+    colour_map::ColourMap,
+    error::ColourMapError,
+    spaces::{
+        AdobeRgb, Cmyk, DisplayP3, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, Lchuv, Luv,
+        Oklab, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+    },
+    traits::Colour,
+};
+
+/// Macro to implement `Serialize` and `Deserialize` for a colour type from its getters and `new` constructor.
+macro_rules! impl_serde {
+    ($colour:ident: $($field:ident),+) => {
+        impl<T: Float + Send + Sync + Serialize> Serialize for $colour<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut state = serializer.serialize_struct(stringify!($colour), [$(stringify!($field)),+].len())?;
+                $(state.serialize_field(stringify!($field), &self.$field())?;)+
+                state.end()
+            }
+        }
+
+        impl<'de, T: Float + Send + Sync + Deserialize<'de>> Deserialize<'de> for $colour<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                /// Unvalidated components, as read from the input.
+                #[derive(Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Components<V> {
+                    $($field: V),+
+                }
+
+                let Components { $($field),+ } = Components::deserialize(deserializer)?;
+                Self::new($($field),+).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+impl_serde!(AdobeRgb: red, green, blue);
+impl_serde!(Cmyk: cyan, magenta, yellow, key);
+impl_serde!(DisplayP3: red, green, blue);
+impl_serde!(Grey: grey);
+impl_serde!(GreyAlpha: grey, alpha);
+impl_serde!(Hpluv: hue, saturation, lightness);
+impl_serde!(Hsl: hue, saturation, lightness);
+impl_serde!(HslAlpha: hue, saturation, lightness, alpha);
+impl_serde!(Hsluv: hue, saturation, lightness);
+impl_serde!(Hsv: hue, saturation, value);
+impl_serde!(HsvAlpha: hue, saturation, value, alpha);
+impl_serde!(Lab: lightness, a_star, b_star);
+impl_serde!(LabAlpha: lightness, a_star, b_star, alpha);
+impl_serde!(Lchuv: lightness, chroma, hue);
+impl_serde!(Luv: lightness, u_star, v_star);
+impl_serde!(Oklab: lightness, a, b);
+impl_serde!(Rgb: red, green, blue);
+impl_serde!(RgbAlpha: red, green, blue, alpha);
+impl_serde!(Srgb: red, green, blue);
+impl_serde!(SrgbAlpha: red, green, blue, alpha);
+impl_serde!(XyY: x, y, big_y);
+impl_serde!(Xyz: x, y, z);
+impl_serde!(XyzAlpha: x, y, z, alpha);
+
+impl<C, T, const N: usize> Serialize for ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N> + Serialize,
+    T: Float + Send + Sync + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ColourMap", 1 + usize::from(self.positions().is_some()))?;
+        state.serialize_field("colours", self.colours())?;
+        if let Some(positions) = self.positions() {
+            state.serialize_field("positions", positions)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de, C, T, const N: usize> Deserialize<'de> for ColourMap<C, T, N>
+where
+    C: Clone + Colour<T, N> + Deserialize<'de>,
+    T: Float + Send + Sync + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Colours and optional positions of the map, as read from the input.
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Stops<V, P> {
+            colours: Vec<V>,
+            positions: Option<Vec<P>>,
+        }
+
+        let Stops { colours, positions } = Stops::deserialize(deserializer)?;
+        let Some(positions) = positions else {
+            return Self::new(&colours).map_err(D::Error::custom);
+        };
+
+        if colours.len() != positions.len() {
+            return Err(D::Error::custom(ColourMapError::MismatchedArrayLengths {
+                colours: colours.len(),
+                positions: positions.len(),
+            }));
+        }
+        let stops: Vec<(C, T)> = colours.into_iter().zip(positions).collect();
+        Self::from_positions(&stops).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+
+    use crate::error::Result as ChromaticResult;
+
+    /// Serialise a colour to JSON, read it back, and check the components survive unchanged.
+    fn assert_round_trip<C, const N: usize>(colour: &C)
+    where
+        C: Colour<f64, N> + Serialize + DeserializeOwned,
+    {
+        let json = serde_json::to_string(colour).expect("serialisation should succeed");
+        let restored: C = serde_json::from_str(&json).expect("deserialisation should succeed");
+        assert_eq!(restored.components().map(f64::to_bits), colour.components().map(f64::to_bits));
+    }
+
+    #[test]
+    fn colours_serialise_to_named_fields() -> ChromaticResult<()> {
+        let json = serde_json::to_string(&Rgb::<f64>::new(0.5, 0.25, 0.125)?).expect("serialisation should succeed");
+        assert_eq!(json, r#"{"red":0.5,"green":0.25,"blue":0.125}"#);
+
+        let json = serde_json::to_string(&RgbAlpha::<f64>::new(0.5, 0.25, 0.125, 1.0)?).expect("serialisation should succeed");
+        assert_eq!(json, r#"{"red":0.5,"green":0.25,"blue":0.125,"alpha":1.0}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn every_space_round_trips_through_json() -> ChromaticResult<()> {
+        assert_round_trip(&Rgb::<f64>::new(0.1, 0.2, 0.3)?);
+        assert_round_trip(&Srgb::<f64>::new(0.1, 0.2, 0.3)?);
+        assert_round_trip(&Hsl::<f64>::new(120.0, 0.5, 0.25)?);
+        assert_round_trip(&Hsv::<f64>::new(240.0, 0.5, 0.75)?);
+        assert_round_trip(&Lab::<f64>::new(50.0, 20.0, -30.0)?);
+        assert_round_trip(&Xyz::<f64>::new(0.3, 0.4, 0.5)?);
+        assert_round_trip(&Grey::<f64>::new(0.5)?);
+        assert_round_trip(&GreyAlpha::<f64>::new(0.5, 0.25)?);
+        assert_round_trip(&HslAlpha::<f64>::new(120.0, 0.5, 0.25, 0.75)?);
+        assert_round_trip(&HsvAlpha::<f64>::new(240.0, 0.5, 0.75, 0.25)?);
+        assert_round_trip(&LabAlpha::<f64>::new(50.0, 20.0, -30.0, 0.5)?);
+        assert_round_trip(&RgbAlpha::<f64>::new(0.1, 0.2, 0.3, 0.4)?);
+        assert_round_trip(&SrgbAlpha::<f64>::new(0.1, 0.2, 0.3, 0.4)?);
+        assert_round_trip(&XyzAlpha::<f64>::new(0.3, 0.4, 0.5, 0.6)?);
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range_components_are_a_deserialisation_error() {
+        assert!(serde_json::from_str::<Rgb<f64>>(r#"{"red":1.5,"green":0.0,"blue":0.0}"#).is_err());
+        assert!(serde_json::from_str::<Hsl<f64>>(r#"{"hue":0.0,"saturation":2.0,"lightness":0.5}"#).is_err());
+        assert!(serde_json::from_str::<Rgb<f64>>(r#"{"red":0.5,"green":0.0}"#).is_err());
+    }
+
+    #[test]
+    fn colour_map_round_trips_through_json() -> ChromaticResult<()> {
+        let map = ColourMap::<Rgb<f64>, f64, 3>::new(&[
+            Rgb::new(1.0, 0.0, 0.0)?,
+            Rgb::new(0.0, 1.0, 0.0)?,
+            Rgb::new(0.0, 0.0, 1.0)?,
+        ])?;
+
+        let json = serde_json::to_string(&map).expect("serialisation should succeed");
+        assert!(json.starts_with(r#"{"colours":["#));
+        let restored: ColourMap<Rgb<f64>, f64, 3> = serde_json::from_str(&json).expect("deserialisation should succeed");
+        assert_eq!(restored.colours(), map.colours());
+        assert_eq!(restored.positions(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_colour_map_is_a_deserialisation_error() {
+        let result = serde_json::from_str::<ColourMap<Rgb<f64>, f64, 3>>(r#"{"colours":[]}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn positioned_colour_map_round_trips_and_validates() -> ChromaticResult<()> {
+        let map = ColourMap::<Rgb<f64>, f64, 3>::from_positions(&[
+            (Rgb::new(0.0, 0.0, 0.0)?, 0.0),
+            (Rgb::new(1.0, 1.0, 1.0)?, 0.25),
+        ])?;
+
+        let json = serde_json::to_string(&map).expect("serialisation should succeed");
+        let restored: ColourMap<Rgb<f64>, f64, 3> = serde_json::from_str(&json).expect("deserialisation should succeed");
+        assert_eq!(restored.positions(), map.positions());
+
+        let mismatched = r#"{"colours":[{"red":0.0,"green":0.0,"blue":0.0}],"positions":[0.0,1.0]}"#;
+        assert!(serde_json::from_str::<ColourMap<Rgb<f64>, f64, 3>>(mismatched).is_err());
+        Ok(())
+    }
+}
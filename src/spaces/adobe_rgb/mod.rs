@@ -0,0 +1,398 @@
+//! Adobe RGB (1998) colour representation.
+//!
+//! Adobe RGB (1998) is an RGB working space with a wider gamut than sRGB, particularly in the
+//! cyan-green region. It shares the D65 white point with sRGB, but uses different primaries
+//! and a pure power-law transfer function with an exponent of exactly 563/256 (2.19921875), so that
+//! decoding a mid-grey of 0.5 gives a linear value of about 0.2178.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        Result, clamp_unit_component, format_terminal_color, safe_constant, validate_interpolation_factor,
+        validate_unit_component,
+    },
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// Adobe RGB (1998) colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct AdobeRgb<T: Float + Send + Sync> {
+    /// Gamma-encoded red component in range [0, 1].
+    red: T,
+    /// Gamma-encoded green component in range [0, 1].
+    green: T,
+    /// Gamma-encoded blue component in range [0, 1].
+    blue: T,
+}
+
+impl<T: Float + Send + Sync> AdobeRgb<T> {
+    /// Create a new `AdobeRgb` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The gamma-encoded red component, must be in range [0, 1]
+    /// * `green` - The gamma-encoded green component, must be in range [0, 1]
+    /// * `blue` - The gamma-encoded blue component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn new(red: T, green: T, blue: T) -> Result<Self> {
+        validate_unit_component(red, "red")?;
+        validate_unit_component(green, "green")?;
+        validate_unit_component(blue, "blue")?;
+
+        Ok(Self { red, green, blue })
+    }
+
+    /// Get the `red` component.
+    pub const fn red(&self) -> T {
+        self.red
+    }
+
+    /// Get the `green` component.
+    pub const fn green(&self) -> T {
+        self.green
+    }
+
+    /// Get the `blue` component.
+    pub const fn blue(&self) -> T {
+        self.blue
+    }
+
+    /// Set the `red` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The new red value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_red(&mut self, red: T) -> Result<()> {
+        validate_unit_component(red, "red")?;
+        self.red = red;
+        Ok(())
+    }
+
+    /// Set the `green` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `green` - The new green value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_green(&mut self, green: T) -> Result<()> {
+        validate_unit_component(green, "green")?;
+        self.green = green;
+        Ok(())
+    }
+
+    /// Set the `blue` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `blue` - The new blue value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_blue(&mut self, blue: T) -> Result<()> {
+        validate_unit_component(blue, "blue")?;
+        self.blue = blue;
+        Ok(())
+    }
+
+    /// Set all components at once with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The red component, must be in range [0, 1]
+    /// * `green` - The green component, must be in range [0, 1]
+    /// * `blue` - The blue component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn set_components(&mut self, red: T, green: T, blue: T) -> Result<()> {
+        validate_unit_component(red, "red")?;
+        validate_unit_component(green, "green")?;
+        validate_unit_component(blue, "blue")?;
+
+        self.red = red;
+        self.green = green;
+        self.blue = blue;
+        Ok(())
+    }
+
+    /// Apply the Adobe RGB (1998) gamma encoding to a linear component.
+    ///
+    /// # Arguments
+    ///
+    /// * `linear` - Linear component value in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mathematical operations fail during encoding.
+    pub fn gamma_encode(linear: T) -> Result<T> {
+        Ok(linear.max(T::zero()).powf(safe_constant(256.0 / 563.0)?))
+    }
+
+    /// Apply the Adobe RGB (1998) gamma decoding to an encoded component.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoded` - Gamma-encoded component value in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mathematical operations fail during decoding.
+    pub fn gamma_decode(encoded: T) -> Result<T> {
+        Ok(encoded.max(T::zero()).powf(safe_constant(563.0 / 256.0)?))
+    }
+
+    /// Create an Adobe RGB colour from an XYZ colour.
+    /// Colours outside the Adobe RGB gamut are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - The XYZ colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion or gamma encoding fails.
+    pub fn from_xyz(xyz: &Xyz<T>) -> Result<Self> {
+        let (x, y, z) = (xyz.x(), xyz.y(), xyz.z());
+
+        // XYZ to linear Adobe RGB using the inverse primaries matrix (D65)
+        let r = x * safe_constant(2.041_369_0)? - y * safe_constant(0.564_946_4)? - z * safe_constant(0.344_694_4)?;
+        let g = -x * safe_constant(0.969_266_0)? + y * safe_constant(1.876_010_8)? + z * safe_constant(0.041_556_0)?;
+        let b = x * safe_constant(0.013_447_4)? - y * safe_constant(0.118_389_7)? + z * safe_constant(1.015_409_6)?;
+
+        // Clamp to [0, 1] range
+        let clamp = |value: T| value.max(T::zero()).min(T::one());
+
+        Self::new(
+            Self::gamma_encode(clamp(r))?,
+            Self::gamma_encode(clamp(g))?,
+            Self::gamma_encode(clamp(b))?,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for AdobeRgb<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        // Hex digits encode the gamma-encoded components directly
+        let [red, green, blue] = Srgb::from_hex(hex)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        Srgb::new(self.red, self.green, self.blue)?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        let [red, green, blue] = Srgb::from_bytes(bytes)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        Srgb::new(self.red, self.green, self.blue)?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        let [red, green, blue] = Srgb::from_bytes16(bytes)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        Srgb::new(self.red, self.green, self.blue)?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components;
+        Self::new(red, green, blue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components.map(clamp_unit_component);
+        Self::new(red, green, blue)
+    }
+
+    /// Linear interpolate between two Adobe RGB colours.
+    /// Note: This performs interpolation on the gamma-encoded components.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.red * (T::one() - t) + rhs.red * t,
+            lhs.green * (T::one() - t) + rhs.green * t,
+            lhs.blue * (T::one() - t) + rhs.blue * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for AdobeRgb<T> {
+    const GAMUT: Gamut = Gamut::AdobeRgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_adobe_rgb()
+    }
+
+    fn to_adobe_rgb(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        // Colours outside the sRGB gamut are clamped
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        let r = Self::gamma_decode(self.red)?;
+        let g = Self::gamma_decode(self.green)?;
+        let b = Self::gamma_decode(self.blue)?;
+
+        // Linear Adobe RGB to XYZ using the primaries matrix (D65)
+        let x = r * safe_constant(0.576_730_9)? + g * safe_constant(0.185_554_0)? + b * safe_constant(0.188_185_2)?;
+        let y = r * safe_constant(0.297_376_9)? + g * safe_constant(0.627_349_1)? + b * safe_constant(0.075_274_1)?;
+        let z = r * safe_constant(0.027_034_3)? + g * safe_constant(0.070_687_2)? + b * safe_constant(0.991_108_5)?;
+
+        // The luminance coefficients sum to slightly more than 1, so clamp to the valid range
+        Xyz::new(x, y.min(T::one()), z)
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for AdobeRgb<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let rgb = self.to_srgb()?;
+        let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pure_green_is_outside_the_srgb_gamut() -> Result<()> {
+        let green = AdobeRgb::<f64>::new(0.0, 1.0, 0.0)?;
+
+        assert!(!green.to_xyz()?.in_srgb_gamut());
+        assert!(AdobeRgb::<f64>::new(0.5, 0.5, 0.5)?.to_xyz()?.in_srgb_gamut());
+        Ok(())
+    }
+
+    #[test]
+    fn xyz_round_trip_is_stable() -> Result<()> {
+        for components in [[0.0, 1.0, 0.0], [0.2, 0.4, 0.8], [1.0, 1.0, 1.0]] {
+            let [red, green, blue] = components;
+            let colour = AdobeRgb::<f64>::new(red, green, blue)?;
+
+            let xyz = colour.to_xyz()?;
+            let round_trip = AdobeRgb::from_xyz(&xyz)?;
+
+            // The power-law curve has an unbounded slope at zero, which amplifies matrix rounding there
+            for (lhs, rhs) in round_trip.components().iter().zip(components) {
+                assert!((lhs - rhs).abs() < 1e-3);
+            }
+            for (lhs, rhs) in round_trip.to_xyz()?.components().iter().zip(xyz.components()) {
+                assert!((lhs - rhs).abs() < 1e-6);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn gamma_uses_the_adobe_exponent() -> Result<()> {
+        let expected = 0.5_f64.powf(563.0 / 256.0);
+
+        assert!((AdobeRgb::<f64>::gamma_decode(0.5)? - expected).abs() < 1e-12);
+        assert!((AdobeRgb::<f64>::gamma_encode(expected)? - 0.5).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn mid_grey_round_trips_through_xyz() -> Result<()> {
+        let grey = AdobeRgb::<f64>::new(0.5, 0.5, 0.5)?;
+
+        let xyz = grey.to_xyz()?;
+        assert!((xyz.y() - 0.5_f64.powf(563.0 / 256.0)).abs() < 1e-4);
+        let restored = AdobeRgb::from_xyz(&xyz)?;
+        for value in restored.components() {
+            assert!((value - 0.5).abs() < 1e-6);
+        }
+        Ok(())
+    }
+}
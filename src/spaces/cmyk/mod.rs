@@ -0,0 +1,371 @@
+//! CMYK colour representation.
+//!
+//! CMYK describes a colour by the amounts of cyan, magenta, yellow and black (key) ink used to print it.
+//! This is the naive device-independent model: components are derived directly from the gamma-encoded
+//! sRGB components, as in most design software, rather than through an ICC print profile.
+//!
+//! Hex strings are sRGB-encoded, as for every other colour space, while byte arrays hold the four
+//! ink amounts directly.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        Result, clamp_unit_component, component_to_u8, component_to_u16, format_terminal_color, safe_constant, u8_to_component,
+        u16_to_component, validate_interpolation_factor, validate_unit_component,
+    },
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// CMYK colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct Cmyk<T: Float + Send + Sync> {
+    /// Cyan component in range [0, 1].
+    cyan: T,
+    /// Magenta component in range [0, 1].
+    magenta: T,
+    /// Yellow component in range [0, 1].
+    yellow: T,
+    /// Key (black) component in range [0, 1].
+    key: T,
+}
+
+impl<T: Float + Send + Sync> Cmyk<T> {
+    /// Create a new `Cmyk` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `cyan` - The cyan component, must be in range [0, 1]
+    /// * `magenta` - The magenta component, must be in range [0, 1]
+    /// * `yellow` - The yellow component, must be in range [0, 1]
+    /// * `key` - The key (black) component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn new(cyan: T, magenta: T, yellow: T, key: T) -> Result<Self> {
+        validate_unit_component(cyan, "cyan")?;
+        validate_unit_component(magenta, "magenta")?;
+        validate_unit_component(yellow, "yellow")?;
+        validate_unit_component(key, "key")?;
+
+        Ok(Self {
+            cyan,
+            magenta,
+            yellow,
+            key,
+        })
+    }
+
+    /// Get the `cyan` component.
+    pub const fn cyan(&self) -> T {
+        self.cyan
+    }
+
+    /// Get the `magenta` component.
+    pub const fn magenta(&self) -> T {
+        self.magenta
+    }
+
+    /// Get the `yellow` component.
+    pub const fn yellow(&self) -> T {
+        self.yellow
+    }
+
+    /// Get the `key` (black) component.
+    pub const fn key(&self) -> T {
+        self.key
+    }
+
+    /// Set the `cyan` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `cyan` - The new cyan value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_cyan(&mut self, cyan: T) -> Result<()> {
+        validate_unit_component(cyan, "cyan")?;
+        self.cyan = cyan;
+        Ok(())
+    }
+
+    /// Set the `magenta` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `magenta` - The new magenta value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_magenta(&mut self, magenta: T) -> Result<()> {
+        validate_unit_component(magenta, "magenta")?;
+        self.magenta = magenta;
+        Ok(())
+    }
+
+    /// Set the `yellow` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `yellow` - The new yellow value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_yellow(&mut self, yellow: T) -> Result<()> {
+        validate_unit_component(yellow, "yellow")?;
+        self.yellow = yellow;
+        Ok(())
+    }
+
+    /// Set the `key` (black) component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The new key value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_key(&mut self, key: T) -> Result<()> {
+        validate_unit_component(key, "key")?;
+        self.key = key;
+        Ok(())
+    }
+
+    /// Create a `Cmyk` colour from an sRGB colour, using as much black as possible.
+    ///
+    /// Pure black maps to `(0, 0, 0, 1)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `srgb` - The sRGB colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn from_srgb(srgb: &Srgb<T>) -> Result<Self> {
+        let key = T::one() - srgb.red().max(srgb.green()).max(srgb.blue());
+
+        // With no light left every ink amount is undefined, so only black ink is used
+        let remaining = T::one() - key;
+        if remaining <= T::zero() {
+            return Self::new(T::zero(), T::zero(), T::zero(), T::one());
+        }
+
+        let ink = |channel: T| clamp_unit_component((remaining - channel) / remaining);
+        Self::new(ink(srgb.red()), ink(srgb.green()), ink(srgb.blue()), key)
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 4> for Cmyk<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_cmyk()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 4]) -> Result<Self> {
+        let scale = safe_constant(255.0)?;
+        let [cyan, magenta, yellow, key] = bytes;
+        Self::new(
+            u8_to_component(cyan, scale)?,
+            u8_to_component(magenta, scale)?,
+            u8_to_component(yellow, scale)?,
+            u8_to_component(key, scale)?,
+        )
+    }
+
+    fn to_bytes(self) -> Result<[u8; 4]> {
+        let scale = safe_constant(255.0)?;
+        Ok([
+            component_to_u8(self.cyan, "cyan", scale)?,
+            component_to_u8(self.magenta, "magenta", scale)?,
+            component_to_u8(self.yellow, "yellow", scale)?,
+            component_to_u8(self.key, "key", scale)?,
+        ])
+    }
+
+    fn from_bytes16(bytes: [u16; 4]) -> Result<Self> {
+        let scale = safe_constant(65535.0)?;
+        let [cyan, magenta, yellow, key] = bytes;
+        Self::new(
+            u16_to_component(cyan, scale)?,
+            u16_to_component(magenta, scale)?,
+            u16_to_component(yellow, scale)?,
+            u16_to_component(key, scale)?,
+        )
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 4]> {
+        let scale = safe_constant(65535.0)?;
+        Ok([
+            component_to_u16(self.cyan, "cyan", scale)?,
+            component_to_u16(self.magenta, "magenta", scale)?,
+            component_to_u16(self.yellow, "yellow", scale)?,
+            component_to_u16(self.key, "key", scale)?,
+        ])
+    }
+
+    fn components(&self) -> [T; 4] {
+        [self.cyan, self.magenta, self.yellow, self.key]
+    }
+
+    fn from_components(components: [T; 4]) -> Result<Self> {
+        let [cyan, magenta, yellow, key] = components;
+        Self::new(cyan, magenta, yellow, key)
+    }
+
+    fn from_components_clamped(components: [T; 4]) -> Result<Self> {
+        let [cyan, magenta, yellow, key] = components.map(clamp_unit_component);
+        Self::new(cyan, magenta, yellow, key)
+    }
+
+    /// Linear interpolate between two CMYK colours, component by component.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.cyan * (T::one() - t) + rhs.cyan * t,
+            lhs.magenta * (T::one() - t) + rhs.magenta * t,
+            lhs.yellow * (T::one() - t) + rhs.yellow * t,
+            lhs.key * (T::one() - t) + rhs.key * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Cmyk<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_cmyk()
+    }
+
+    fn to_cmyk(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_rgb()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_rgb()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_srgb()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        let light = T::one() - self.key;
+        Srgb::new(
+            (T::one() - self.cyan) * light,
+            (T::one() - self.magenta) * light,
+            (T::one() - self.yellow) * light,
+        )
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        self.to_rgb()?.to_xyz()
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Cmyk<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn black_maps_to_full_key() -> Result<()> {
+        let black = Srgb::<f64>::new(0.0, 0.0, 0.0)?.to_cmyk()?;
+
+        assert_eq!(black.components().map(f64::to_bits), [0.0, 0.0, 0.0, 1.0].map(f64::to_bits));
+        Ok(())
+    }
+
+    #[test]
+    fn primaries_and_white_use_standard_inks() -> Result<()> {
+        let white = Srgb::<f64>::new(1.0, 1.0, 1.0)?.to_cmyk()?;
+        let red = Srgb::<f64>::new(1.0, 0.0, 0.0)?.to_cmyk()?;
+
+        assert_eq!(white.components().map(f64::to_bits), [0.0, 0.0, 0.0, 0.0].map(f64::to_bits));
+        assert_eq!(red.components().map(f64::to_bits), [0.0, 1.0, 1.0, 0.0].map(f64::to_bits));
+        Ok(())
+    }
+
+    #[test]
+    fn srgb_round_trips_through_cmyk() -> Result<()> {
+        let colour = Srgb::<f64>::new(0.2, 0.5, 0.8)?;
+
+        let restored = colour.to_cmyk()?.to_srgb()?;
+        for (lhs, rhs) in restored.components().iter().zip(colour.components()) {
+            assert!((lhs - rhs).abs() < 1e-12);
+        }
+        Ok(())
+    }
+}
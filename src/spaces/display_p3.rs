@@ -0,0 +1,345 @@
+//! Display P3 colour representation.
+//!
+//! Display P3 is the RGB space used by wide-gamut Apple displays and CSS `color(display-p3 ...)`.
+//! It uses the DCI-P3 primaries, which extend further than sRGB into the reds and greens, with the
+//! D65 white point and the same piecewise transfer function as sRGB.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        Result, clamp_unit_component, format_terminal_color, safe_constant, validate_interpolation_factor,
+        validate_unit_component,
+    },
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// Display P3 colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayP3<T: Float + Send + Sync> {
+    /// Gamma-encoded red component in range [0, 1].
+    red: T,
+    /// Gamma-encoded green component in range [0, 1].
+    green: T,
+    /// Gamma-encoded blue component in range [0, 1].
+    blue: T,
+}
+
+impl<T: Float + Send + Sync> DisplayP3<T> {
+    /// Create a new `DisplayP3` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The gamma-encoded red component, must be in range [0, 1]
+    /// * `green` - The gamma-encoded green component, must be in range [0, 1]
+    /// * `blue` - The gamma-encoded blue component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn new(red: T, green: T, blue: T) -> Result<Self> {
+        validate_unit_component(red, "red")?;
+        validate_unit_component(green, "green")?;
+        validate_unit_component(blue, "blue")?;
+
+        Ok(Self { red, green, blue })
+    }
+
+    /// Get the `red` component.
+    pub const fn red(&self) -> T {
+        self.red
+    }
+
+    /// Get the `green` component.
+    pub const fn green(&self) -> T {
+        self.green
+    }
+
+    /// Get the `blue` component.
+    pub const fn blue(&self) -> T {
+        self.blue
+    }
+
+    /// Set the `red` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The new red value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_red(&mut self, red: T) -> Result<()> {
+        validate_unit_component(red, "red")?;
+        self.red = red;
+        Ok(())
+    }
+
+    /// Set the `green` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `green` - The new green value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_green(&mut self, green: T) -> Result<()> {
+        validate_unit_component(green, "green")?;
+        self.green = green;
+        Ok(())
+    }
+
+    /// Set the `blue` component.
+    ///
+    /// # Arguments
+    ///
+    /// * `blue` - The new blue value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_blue(&mut self, blue: T) -> Result<()> {
+        validate_unit_component(blue, "blue")?;
+        self.blue = blue;
+        Ok(())
+    }
+
+    /// Set all components at once with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The red component, must be in range [0, 1]
+    /// * `green` - The green component, must be in range [0, 1]
+    /// * `blue` - The blue component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn set_components(&mut self, red: T, green: T, blue: T) -> Result<()> {
+        validate_unit_component(red, "red")?;
+        validate_unit_component(green, "green")?;
+        validate_unit_component(blue, "blue")?;
+
+        self.red = red;
+        self.green = green;
+        self.blue = blue;
+        Ok(())
+    }
+
+    /// Create a Display P3 colour from an XYZ colour.
+    /// Colours outside the Display P3 gamut are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - The XYZ colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion or gamma encoding fails.
+    pub fn from_xyz(xyz: &Xyz<T>) -> Result<Self> {
+        let (x, y, z) = (xyz.x(), xyz.y(), xyz.z());
+
+        // XYZ to linear Display P3 using the inverse primaries matrix (D65)
+        let r = x * safe_constant(2.493_496_9)? - y * safe_constant(0.931_383_6)? - z * safe_constant(0.402_710_8)?;
+        let g = -x * safe_constant(0.829_489_0)? + y * safe_constant(1.762_664_1)? + z * safe_constant(0.023_624_7)?;
+        let b = x * safe_constant(0.035_845_8)? - y * safe_constant(0.076_172_4)? + z * safe_constant(0.956_884_5)?;
+
+        // Clamp to [0, 1] range
+        let clamp = |value: T| value.max(T::zero()).min(T::one());
+
+        Self::new(
+            Srgb::gamma_encode(clamp(r))?,
+            Srgb::gamma_encode(clamp(g))?,
+            Srgb::gamma_encode(clamp(b))?,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for DisplayP3<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        // Hex digits encode the gamma-encoded components directly
+        let [red, green, blue] = Srgb::from_hex(hex)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        Srgb::new(self.red, self.green, self.blue)?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        let [red, green, blue] = Srgb::from_bytes(bytes)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        Srgb::new(self.red, self.green, self.blue)?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        let [red, green, blue] = Srgb::from_bytes16(bytes)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        Srgb::new(self.red, self.green, self.blue)?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components;
+        Self::new(red, green, blue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components.map(clamp_unit_component);
+        Self::new(red, green, blue)
+    }
+
+    /// Linear interpolate between two Display P3 colours.
+    /// Note: This performs interpolation on the gamma-encoded components.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.red * (T::one() - t) + rhs.red * t,
+            lhs.green * (T::one() - t) + rhs.green * t,
+            lhs.blue * (T::one() - t) + rhs.blue * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for DisplayP3<T> {
+    const GAMUT: Gamut = Gamut::DisplayP3;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_display_p3()
+    }
+
+    fn to_display_p3(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        // Colours outside the sRGB gamut are clamped
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        let r = Srgb::gamma_decode(self.red)?;
+        let g = Srgb::gamma_decode(self.green)?;
+        let b = Srgb::gamma_decode(self.blue)?;
+
+        // Linear Display P3 to XYZ using the primaries matrix (D65)
+        let x = r * safe_constant(0.486_570_9)? + g * safe_constant(0.265_667_7)? + b * safe_constant(0.198_217_3)?;
+        let y = r * safe_constant(0.228_974_6)? + g * safe_constant(0.691_738_5)? + b * safe_constant(0.079_286_9)?;
+        let z = g * safe_constant(0.045_113_4)? + b * safe_constant(1.043_944_4)?;
+
+        // Rounding can leave the luminance of white marginally above 1, so clamp to the valid range
+        Xyz::new(x, y.min(T::one()), z)
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for DisplayP3<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let rgb = self.to_srgb()?;
+        let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p3_red_lies_outside_srgb_before_clamping() -> Result<()> {
+        let red = DisplayP3::<f64>::new(1.0, 0.0, 0.0)?;
+
+        let (r, g, b) = red.to_xyz()?.linear_rgb_unclamped()?;
+        assert!((r - 1.2249).abs() < 1e-3);
+        assert!(g < 0.0 && b < 0.0);
+
+        let clamped = red.to_srgb()?;
+        assert!((clamped.red() - 1.0).abs() < 1e-4);
+        assert!(clamped.green().abs() < 1e-4 && clamped.blue().abs() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn xyz_round_trip_is_stable() -> Result<()> {
+        let colour = DisplayP3::<f64>::new(0.2, 0.6, 0.9)?;
+
+        let restored = DisplayP3::from_xyz(&colour.to_xyz()?)?;
+        for (value, expected) in restored.components().iter().zip(colour.components()) {
+            assert!((value - expected).abs() < 1e-4);
+        }
+        Ok(())
+    }
+}
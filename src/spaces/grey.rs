@@ -1,16 +1,23 @@
 //! Monochrome colour representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
     error::{
-        ColourParsingError, Result, component_to_u8, format_terminal_color, parse_hex_component, safe_constant,
-        u8_to_component, validate_interpolation_factor, validate_unit_component,
+        ColourParsingError, Result, clamp_unit_component, component_to_u8, format_terminal_color, parse_hex_component,
+        safe_constant, u8_to_component, validate_interpolation_factor, validate_unit_component,
     },
     spaces::{GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
 /// Monochrome colour.
@@ -107,6 +114,20 @@ impl<T: Float + Send + Sync> Colour<T, 1> for Grey<T> {
         Ok([value])
     }
 
+    fn components(&self) -> [T; 1] {
+        [self.grey]
+    }
+
+    fn from_components(components: [T; 1]) -> Result<Self> {
+        let [grey] = components;
+        Self::new(grey)
+    }
+
+    fn from_components_clamped(components: [T; 1]) -> Result<Self> {
+        let [grey] = components;
+        Self::new(clamp_unit_component(grey))
+    }
+
     fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
         validate_interpolation_factor(t)?;
         Self::new(lhs.grey() * (T::one() - t) + rhs.grey() * t)
@@ -114,6 +135,12 @@ impl<T: Float + Send + Sync> Colour<T, 1> for Grey<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Grey<T> {
+    const GAMUT: Gamut = Gamut::Grey;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_grey()
+    }
+
     fn to_grey(&self) -> Result<Self> {
         Ok(*self)
     }
@@ -123,12 +150,13 @@ impl<T: Float + Send + Sync> Convert<T> for Grey<T> {
     }
 
     fn to_hsl(&self) -> Result<Hsl<T>> {
-        // For greyscale, hue is undefined (0), saturation is 0, and lightness equals the grey value
-        Hsl::new(T::zero(), T::zero(), self.grey)
+        // For greyscale, hue is undefined (0), saturation is 0, and lightness is the gamma-encoded grey value
+        Hsl::new(T::zero(), T::zero(), Srgb::gamma_encode(self.grey)?)
     }
 
     fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
-        HslAlpha::new(T::zero(), T::zero(), self.grey, T::one())
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
     }
 
     fn to_hsv(&self) -> Result<Hsv<T>> {
@@ -193,7 +221,29 @@ impl<T: Float + Send + Sync> Convert<T> for Grey<T> {
 
 impl<T: Float + Send + Sync> Display for Grey<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let color_string = format_terminal_color(self.grey, self.grey, self.grey, PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+/// Compares colours at byte precision, so colours that encode to the same `to_bytes` value are equal.
+/// Use `ApproxEq` to compare the float components directly.
+impl<T: Float + Send + Sync> PartialEq for Grey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ok() == other.to_bytes().ok()
+    }
+}
+
+impl<T: Float + Send + Sync> Eq for Grey<T> {}
+
+/// Hashes the `to_bytes` value, so colours differing by less than one byte step hash alike.
+/// This is byte precision rather than float exact, consistent with the `PartialEq` implementation.
+impl<T: Float + Send + Sync> Hash for Grey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().ok().hash(state);
+    }
+}
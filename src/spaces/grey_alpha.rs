@@ -1,13 +1,18 @@
 //! Monochrome colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     error::{Result, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// Grey with alpha channel.
@@ -117,3 +122,4 @@ impl<T: Float + Send + Sync> GreyAlpha<T> {
 impl_transparent_colour!(GreyAlpha<T>, Grey<T>, 1);
 impl_transparent_convert!(GreyAlpha<T>, Grey<T>);
 impl_transparent_display!(GreyAlpha<T>);
+impl_transparent_alpha!(GreyAlpha<T>);
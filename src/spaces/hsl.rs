@@ -1,19 +1,26 @@
 //! HSL (Hue, Saturation, Lightness) colour representation.
 
+use alloc::{format, string::String};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Mul, Neg},
+};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
     error::{
-        InterpolationError, Result, format_terminal_color, normalize_hue, safe_constant, validate_interpolation_factor,
+        InterpolationError, Result, clamp_unit_component, component_to_u8, component_to_u16, format_terminal_color,
+        normalize_hue, parse_css_fraction, parse_css_function, parse_css_number, safe_constant, validate_interpolation_factor,
         validate_unit_component,
     },
     spaces::{Grey, GreyAlpha, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
 /// HSL colour representation.
+///
+/// As in CSS, the cylinder is built over the gamma-encoded sRGB channels, so `hsl(0, 100%, 25%)` is `#800000`.
 #[derive(Debug, Clone, Copy)]
 pub struct Hsl<T: Float + Send + Sync> {
     /// Hue component in degrees [0, 360).
@@ -49,6 +56,32 @@ impl<T: Float + Send + Sync> Hsl<T> {
         })
     }
 
+    /// Parse a colour from CSS `hsl()` functional notation (e.g. `hsl(120, 50%, 50%)`).
+    ///
+    /// The hue is in degrees with an optional `deg` suffix, and saturation and lightness are percentages
+    /// in [0, 100] with an optional `%` suffix. Like the components, these describe the sRGB-encoded channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - The CSS colour string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is malformed or saturation or lightness is out of range.
+    pub fn from_css(css: &str) -> Result<Self> {
+        let percentage = |value: &str, component_name: &str| -> Result<T> {
+            parse_css_fraction(value.strip_suffix('%').unwrap_or(value), component_name, 100.0)
+        };
+
+        let [hue, saturation, lightness] = parse_css_function(css, "hsl")?;
+        let hue = parse_css_number(hue.strip_suffix("deg").unwrap_or(hue), "hue")?;
+        Self::new(
+            safe_constant(hue)?,
+            percentage(saturation, "saturation")?,
+            percentage(lightness, "lightness")?,
+        )
+    }
+
     /// Get the `hue` component in degrees [0, 360).
     pub const fn hue(&self) -> T {
         self.hue
@@ -108,6 +141,88 @@ impl<T: Float + Send + Sync> Hsl<T> {
         Ok(())
     }
 
+    /// Create a copy of the colour with its hue rotated, wrapping into [0, 360).
+    ///
+    /// # Arguments
+    ///
+    /// * `degrees` - The rotation in degrees, positive or negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rotated hue is not finite.
+    pub fn rotate_hue(&self, degrees: T) -> Result<Self> {
+        Ok(Self {
+            hue: normalize_hue(self.hue + degrees)?,
+            ..*self
+        })
+    }
+
+    /// Get the complementary colour, with the hue rotated by 180 degrees.
+    #[must_use]
+    pub fn complementary(&self) -> Self {
+        -*self
+    }
+
+    /// Get a triadic scheme: this colour followed by copies with the hue rotated by +120 and -120 degrees.
+    #[must_use]
+    pub fn triadic(&self) -> [Self; 3] {
+        let f120 = T::from(120).unwrap_or_else(T::zero);
+        [*self, self.offset_hue(f120), self.offset_hue(-f120)]
+    }
+
+    /// Get an analogous scheme: this colour followed by copies with the hue rotated by +`spread` and -`spread`.
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - The hue rotation of the neighbouring colours in degrees, typically around 30
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `spread` is not finite.
+    pub fn analogous(&self, spread: T) -> Result<[Self; 3]> {
+        Ok([*self, self.rotate_hue(spread)?, self.rotate_hue(-spread)?])
+    }
+
+    /// Rotate the hue by a finite number of degrees, wrapping into [0, 360).
+    fn offset_hue(&self, degrees: T) -> Self {
+        let f360 = T::from(360).unwrap_or_else(T::zero);
+        let hue = (self.hue + degrees) % f360;
+        Self {
+            hue: if hue < T::zero() { hue + f360 } else { hue },
+            ..*self
+        }
+    }
+
+    /// Create a copy of the colour with its saturation multiplied by a factor, clamped to [0, 1].
+    ///
+    /// A factor of 0 gives a grey of the same lightness, and factors above 1 saturate up to the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The factor to multiply the saturation by
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn scale_saturation(&self, factor: T) -> Result<Self> {
+        Self::new(self.hue, clamp_unit_component(self.saturation * factor), self.lightness)
+    }
+
+    /// Create a copy of the colour with its lightness multiplied by a factor, clamped to [0, 1].
+    ///
+    /// A factor of 0 gives black, and factors above 1 brighten up to white.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The factor to multiply the lightness by
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn scale_lightness(&self, factor: T) -> Result<Self> {
+        Self::new(self.hue, self.saturation, clamp_unit_component(self.lightness * factor))
+    }
+
     /// Helper function for HSL to RGB conversion.
     fn hue_to_rgb(p: T, q: T, mut t: T) -> Result<T> {
         let f6 = safe_constant(6.0)?;
@@ -137,19 +252,42 @@ impl<T: Float + Send + Sync> Hsl<T> {
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Hsl<T> {
     fn from_hex(hex: &str) -> Result<Self> {
-        Rgb::from_hex(hex)?.to_hsl()
+        Srgb::from_hex(hex)?.to_hsl()
     }
 
     fn to_hex(&self) -> Result<String> {
-        self.to_rgb()?.to_hex()
+        self.to_srgb()?.to_hex()
     }
 
     fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
-        Rgb::from_bytes(bytes)?.to_hsl()
+        Srgb::from_bytes(bytes)?.to_hsl()
     }
 
     fn to_bytes(self) -> Result<[u8; 3]> {
-        self.to_rgb()?.to_bytes()
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_hsl()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.hue, self.saturation, self.lightness]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [hue, saturation, lightness] = components;
+        Self::new(hue, saturation, lightness)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        // The hue is wrapped by `new`, so only the remaining components need clamping
+        let [hue, saturation, lightness] = components;
+        Self::new(hue, clamp_unit_component(saturation), clamp_unit_component(lightness))
     }
 
     /// Linear interpolate between two HSL colours.
@@ -197,12 +335,28 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Hsl<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Hsl<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    /// Format as CSS `hsl()` functional notation, with each component rounded to an integer
+    /// (e.g. `hsl(120, 50%, 50%)`). The components describe the sRGB-encoded channels, as browsers expect.
+    fn to_css_string(&self) -> Result<String> {
+        let hue = component_to_u16(self.hue, "hue", T::one())? % 360;
+        let percent = safe_constant(100.0)?;
+        let saturation = component_to_u8(self.saturation, "saturation", percent)?;
+        let lightness = component_to_u8(self.lightness, "lightness", percent)?;
+        Ok(format!("hsl({hue}, {saturation}%, {lightness}%)"))
+    }
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_hsl()
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
-        Grey::new(self.lightness)
+        Grey::new(Srgb::gamma_decode(self.lightness)?)
     }
 
     fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
-        GreyAlpha::new(self.lightness, T::one())
+        GreyAlpha::new(self.to_grey()?.grey(), T::one())
     }
 
     fn to_hsl(&self) -> Result<Self> {
@@ -214,27 +368,8 @@ impl<T: Float + Send + Sync> Convert<T> for Hsl<T> {
     }
 
     fn to_hsv(&self) -> Result<Hsv<T>> {
-        // Convert HSL to HSV
-        // v = L + S_l * min(L, 1-L)
-        let min_lightness = (T::one() - self.lightness).min(self.lightness);
-        let delta = self.saturation * min_lightness;
-        let mut value = self.lightness + delta;
-
-        // Clamp value to [0,1]
-        if value < T::zero() {
-            value = T::zero();
-        } else if value > T::one() {
-            value = T::one();
-        }
-
-        // s_v = 2 * delta / v (or 0 when v≈0)
-        let saturation = if value.abs() <= T::epsilon() {
-            T::zero()
-        } else {
-            safe_constant::<f64, T>(2.0)? * delta / value
-        };
-
-        Hsv::new(self.hue, saturation, value)
+        // HSV is defined over linear RGB, so convert through it rather than between the cylinders directly
+        self.to_rgb()?.to_hsv()
     }
 
     fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
@@ -253,12 +388,24 @@ impl<T: Float + Send + Sync> Convert<T> for Hsl<T> {
     }
 
     fn to_rgb(&self) -> Result<Rgb<T>> {
+        // Convert HSL to linear RGB via gamma-encoded sRGB
+        self.to_srgb()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        // HSL is defined over the gamma-encoded sRGB channels, as in CSS
         let lightness = self.lightness;
         let saturation = self.saturation;
 
         // If saturation is 0, the color is a shade of gray
-        if saturation.abs() < T::epsilon() {
-            return Rgb::new(lightness, lightness, lightness);
+        // Return the lightness directly, so achromatic colours round-trip exactly whatever the hue
+        if saturation == T::zero() {
+            return Srgb::new(lightness, lightness, lightness);
         }
 
         let q = if lightness < safe_constant(0.5)? {
@@ -274,22 +421,7 @@ impl<T: Float + Send + Sync> Convert<T> for Hsl<T> {
         let g = Self::hue_to_rgb(p, q, h)?;
         let b = Self::hue_to_rgb(p, q, h - safe_constant(1.0 / 3.0)?)?;
 
-        Rgb::new(r, g, b)
-    }
-
-    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
-        let rgb = self.to_rgb()?;
-        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
-    }
-
-    fn to_srgb(&self) -> Result<Srgb<T>> {
-        // Convert HSL to sRGB via linear RGB
-        let rgb = self.to_rgb()?;
-        let r_srgb = Srgb::gamma_encode(rgb.red())?;
-        let g_srgb = Srgb::gamma_encode(rgb.green())?;
-        let b_srgb = Srgb::gamma_encode(rgb.blue())?;
-
-        Srgb::new(r_srgb, g_srgb, b_srgb)
+        Srgb::new(r, g, b)
     }
 
     fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
@@ -310,8 +442,198 @@ impl<T: Float + Send + Sync> Convert<T> for Hsl<T> {
 
 impl<T: Float + Send + Sync> Display for Hsl<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let rgb = self.to_rgb()?;
         let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+/// Negation yields the complementary colour.
+///
+/// The hue is rotated by 180 degrees (wrapping into [0, 360)), while saturation and lightness are unchanged.
+/// Note that this is a hue complement, not an RGB inversion.
+impl<T: Float + Send + Sync> Neg for Hsl<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let f180 = T::from(180).unwrap_or_else(T::zero);
+        let f360 = f180 + f180;
+
+        let hue = self.hue + f180;
+        Self {
+            hue: if hue >= f360 { hue - f360 } else { hue },
+            saturation: self.saturation,
+            lightness: self.lightness,
+        }
+    }
+}
+
+/// Scalar multiplication scales both saturation and lightness by the factor.
+///
+/// The hue is unchanged, and the scaled saturation and lightness are clamped to [0, 1],
+/// so factors above 1 saturate and brighten up to the limit, and negative factors yield black.
+impl<T: Float + Send + Sync> Mul<T> for Hsl<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Self {
+            hue: self.hue,
+            saturation: (self.saturation * factor).max(T::zero()).min(T::one()),
+            lightness: (self.lightness * factor).max(T::zero()).min(T::one()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ChromaticError;
+
+    #[test]
+    fn neg_gives_complementary_hue() -> Result<()> {
+        let colour = Hsl::<f64>::new(30.0, 0.6, 0.4)?;
+
+        let complement = -colour;
+        assert!((complement.hue() - 210.0).abs() < 1e-12);
+        assert!((complement.saturation() - 0.6).abs() < 1e-12);
+        assert!(((-Hsl::<f64>::new(270.0, 0.6, 0.4)?).hue() - 90.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn mul_scales_saturation_and_lightness() -> Result<()> {
+        let colour = Hsl::<f64>::new(30.0, 0.6, 0.4)?;
+
+        let halved = colour * 0.5;
+        assert!((halved.hue() - 30.0).abs() < 1e-12);
+        assert!((halved.saturation() - 0.3).abs() < 1e-12);
+        assert!((halved.lightness() - 0.2).abs() < 1e-12);
+
+        let boosted = colour * 2.0;
+        assert!((boosted.saturation() - 1.0).abs() < 1e-12);
+        assert!((boosted.lightness() - 0.8).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn achromatic_srgb_round_trips_exactly() -> Result<()> {
+        for value in [0.0, 0.1, 1.0 / 3.0, 0.5, 0.737, 1.0] {
+            let grey = Srgb::<f64>::new(value, value, value)?;
+
+            let hsl = grey.to_hsl()?;
+            assert!(hsl.saturation().abs() < f64::EPSILON);
+            let round_trip = hsl.to_srgb()?;
+            assert_eq!(round_trip.components().map(f64::to_bits), grey.components().map(f64::to_bits));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_components_clamped_wraps_hue_and_clamps_the_rest() -> Result<()> {
+        let colour = Hsl::<f64>::from_components_clamped([390.0, 1.5, -0.2])?;
+
+        assert!((colour.hue() - 30.0).abs() < 1e-12);
+        assert!((colour.saturation() - 1.0).abs() < 1e-12);
+        assert!(colour.lightness().abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_accepts_degrees_and_percentages() -> Result<()> {
+        for css in [
+            "hsl(120, 50%, 50%)",
+            "hsl(120deg, 50%, 50%)",
+            "hsl(120 50 50)",
+            " HSL( 120deg 50% 50% ) ",
+        ] {
+            let colour = Hsl::<f64>::from_css(css)?;
+            assert!((colour.hue() - 120.0).abs() < 1e-12);
+            assert!((colour.saturation() - 0.5).abs() < 1e-12);
+            assert!((colour.lightness() - 0.5).abs() < 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_reports_malformed_input() {
+        for css in [
+            "hsl(120, 50%)",
+            "hsl(120, 150%, 50%)",
+            "hsl(abc, 50%, 50%)",
+            "rgb(120, 50%, 50%)",
+        ] {
+            assert!(matches!(Hsl::<f64>::from_css(css), Err(ChromaticError::ColourParsing(_))));
+        }
+    }
+
+    #[test]
+    fn rotate_hue_wraps_in_both_directions() -> Result<()> {
+        let red = Hsl::<f64>::new(0.0, 1.0, 0.5)?;
+
+        let green = red.rotate_hue(120.0)?;
+        assert!((green.hue() - 120.0).abs() < 1e-12);
+        let srgb = green.to_srgb()?;
+        assert!((srgb.green() - 1.0).abs() < 1e-12 && srgb.red().abs() < 1e-12 && srgb.blue().abs() < 1e-12);
+
+        assert!((Hsl::<f64>::new(350.0, 1.0, 0.5)?.rotate_hue(20.0)?.hue() - 10.0).abs() < 1e-9);
+        assert!((red.rotate_hue(-30.0)?.hue() - 330.0).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_saturation_scale_gives_grey_of_same_lightness() -> Result<()> {
+        let colour = Hsl::<f64>::new(200.0, 0.8, 0.4)?;
+
+        let grey = colour.scale_saturation(0.0)?;
+        assert!(grey.saturation().abs() < 1e-12);
+        assert!((grey.lightness() - 0.4).abs() < 1e-12);
+        let rgb = grey.to_rgb()?;
+        assert!((rgb.red() - rgb.green()).abs() < 1e-12 && (rgb.green() - rgb.blue()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn scaling_up_clamps_to_unit_range() -> Result<()> {
+        let colour = Hsl::<f64>::new(200.0, 0.6, 0.4)?;
+
+        assert!((colour.scale_saturation(1.5)?.saturation() - 0.9).abs() < 1e-12);
+        assert!((colour.scale_saturation(3.0)?.saturation() - 1.0).abs() < 1e-12);
+        assert!((colour.scale_lightness(0.5)?.lightness() - 0.2).abs() < 1e-12);
+        assert!((colour.scale_lightness(4.0)?.lightness() - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn harmonic_schemes_use_exact_hue_offsets() -> Result<()> {
+        let base = Hsl::<f64>::new(30.0, 0.7, 0.4)?;
+
+        assert_eq!(base.complementary().hue().to_bits(), 210.0_f64.to_bits());
+        let [first, plus, minus] = base.triadic();
+        assert_eq!(
+            [first.hue(), plus.hue(), minus.hue()].map(f64::to_bits),
+            [30.0, 150.0, 270.0].map(f64::to_bits)
+        );
+        let [centre, up, down] = base.analogous(30.0)?;
+        assert_eq!(
+            [centre.hue(), up.hue(), down.hue()].map(f64::to_bits),
+            [30.0, 60.0, 0.0].map(f64::to_bits)
+        );
+        assert!((plus.saturation() - 0.7).abs() < 1e-12 && (plus.lightness() - 0.4).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn css_and_hex_describe_srgb_encoded_channels() -> Result<()> {
+        assert_eq!(Hsl::<f64>::from_css("hsl(0, 100%, 25%)")?.to_hex()?, "#800000");
+        assert_eq!(Hsl::<f64>::from_css("hsl(240, 100%, 75%)")?.to_hex()?, "#8080FF");
+
+        let grey = Hsl::<f64>::from_hex("#808080")?;
+        assert!((grey.lightness() - 128.0 / 255.0).abs() < 1e-12);
+        assert_eq!(grey.to_hex()?, "#808080");
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! sRGB gamut boundary maths for the `HSLuv` family of colour spaces.
+//!
+//! The boundaries are derived from the same XYZ to linear RGB matrix used by `Xyz`, so that colours
+//! at the maximum chroma map onto the edge of the sRGB gamut.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{
+    error::{Result, safe_constant},
+    spaces::{
+        luv::transform::{lightness_to_y, white_chromaticity},
+        xyz::XYZ_TO_LINEAR_RGB,
+    },
+};
+
+/// Lightness above which a colour is treated as pure white.
+const WHITE_LIGHTNESS: f64 = 99.999_999_9;
+
+/// Lightness below which a colour is treated as pure black.
+const BLACK_LIGHTNESS: f64 = 1e-8;
+
+/// Check whether a lightness is so close to black or white that the colour is achromatic.
+pub(super) fn is_achromatic_lightness<T: Float + Send + Sync>(lightness: T) -> Result<bool> {
+    Ok(lightness > safe_constant(WHITE_LIGHTNESS)? || lightness < safe_constant(BLACK_LIGHTNESS)?)
+}
+
+/// Maximum chroma within the sRGB gamut for a given lightness and hue.
+pub(super) fn max_chroma_for_lightness_hue<T: Float + Send + Sync>(lightness: T, hue: T) -> Result<T> {
+    let (sin, cos) = hue.to_radians().sin_cos();
+
+    // Distance along the hue ray to each boundary line, keeping the nearest one in front of the origin
+    let mut max_chroma = T::infinity();
+    for [a, b, c] in gamut_bounds(lightness)? {
+        let length = -c / (a * cos + b * sin);
+        if length >= T::zero() {
+            max_chroma = max_chroma.min(length);
+        }
+    }
+
+    Ok(if max_chroma.is_finite() { max_chroma } else { T::zero() })
+}
+
+/// Maximum chroma within the sRGB gamut for a given lightness, valid for every hue.
+pub(super) fn max_safe_chroma_for_lightness<T: Float + Send + Sync>(lightness: T) -> Result<T> {
+    // Perpendicular distance from the origin to each boundary line
+    Ok(gamut_bounds(lightness)?
+        .iter()
+        .map(|&[a, b, c]| c.abs() / a.hypot(b))
+        .fold(T::infinity(), T::min))
+}
+
+/// Lines `a*u + b*v + c = 0` in the u*v* plane along which each linear RGB channel is 0 or 1 at a given lightness.
+fn gamut_bounds<T: Float + Send + Sync>(lightness: T) -> Result<Vec<[T; 3]>> {
+    let (white_u, white_v) = white_chromaticity()?;
+    let y = lightness_to_y(lightness)?;
+    let scale = safe_constant::<f64, T>(13.0)? * lightness;
+
+    let mut bounds = Vec::with_capacity(6);
+    for [m1, m2, m3] in XYZ_TO_LINEAR_RGB {
+        let (m1, m2, m3) = (
+            safe_constant::<f64, T>(m1)?,
+            safe_constant::<f64, T>(m2)?,
+            safe_constant::<f64, T>(m3)?,
+        );
+        for channel in [T::zero(), T::one()] {
+            // Substituting X and Z in terms of u' and v' at fixed Y gives a line in the u'v' plane
+            let a = safe_constant::<f64, T>(9.0)? * m1 - safe_constant::<f64, T>(3.0)? * m3;
+            let b = safe_constant::<f64, T>(4.0)? * m2
+                - safe_constant::<f64, T>(20.0)? * m3
+                - safe_constant::<f64, T>(4.0)? * channel / y;
+            let c = scale * (a * white_u + b * white_v + safe_constant::<f64, T>(12.0)? * m3);
+            bounds.push([a, b, c]);
+        }
+    }
+
+    Ok(bounds)
+}
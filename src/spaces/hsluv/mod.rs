@@ -0,0 +1,354 @@
+//! `HSLuv` and `HPLuv` colour representations.
+//!
+//! `HSLuv` is a human-friendly alternative to HSL built on CIE `LCh(uv)`. Lightness is perceptual lightness,
+//! so colours with equal lightness look equally light across all hues, and saturation is the fraction of the
+//! maximum chroma that stays inside the sRGB gamut for that lightness and hue.
+//!
+//! `HPLuv` uses the largest chroma that is in gamut for every hue at a given lightness instead, so saturation
+//! is comparable between hues at the cost of only covering pastel colours.
+
+mod gamut;
+mod hpluv;
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        InterpolationError, Result, clamp_unit_component, format_terminal_color, normalize_hue, safe_constant,
+        validate_interpolation_factor, validate_unit_component,
+    },
+    spaces::{
+        Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha,
+        luv::transform::{lch_to_xyz, xyz_to_lch},
+    },
+    traits::{Colour, Convert, Gamut},
+};
+
+pub use hpluv::Hpluv;
+
+/// `HSLuv` colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct Hsluv<T: Float + Send + Sync> {
+    /// Hue component in degrees [0, 360).
+    hue: T,
+    /// Saturation component as a fraction of the maximum in-gamut chroma [0, 1].
+    saturation: T,
+    /// Perceptual lightness component [0, 1].
+    lightness: T,
+}
+
+impl<T: Float + Send + Sync> Hsluv<T> {
+    /// Create a new `Hsluv` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The hue in degrees, will be normalized to [0, 360)
+    /// * `saturation` - The saturation, must be in range [0, 1]
+    /// * `lightness` - The lightness, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saturation or lightness are outside [0, 1],
+    /// or if hue normalization fails.
+    pub fn new(hue: T, saturation: T, lightness: T) -> Result<Self> {
+        let normalized_hue = normalize_hue(hue)?;
+        validate_unit_component(saturation, "saturation")?;
+        validate_unit_component(lightness, "lightness")?;
+
+        Ok(Self {
+            hue: normalized_hue,
+            saturation,
+            lightness,
+        })
+    }
+
+    /// Get the `hue` component in degrees [0, 360).
+    pub const fn hue(&self) -> T {
+        self.hue
+    }
+
+    /// Get the `saturation` component.
+    pub const fn saturation(&self) -> T {
+        self.saturation
+    }
+
+    /// Get the `lightness` component.
+    pub const fn lightness(&self) -> T {
+        self.lightness
+    }
+
+    /// Set the `hue` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The new hue in degrees, will be normalized to [0, 360)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if hue normalization fails.
+    pub fn set_hue(&mut self, hue: T) -> Result<()> {
+        self.hue = normalize_hue(hue)?;
+        Ok(())
+    }
+
+    /// Set the `saturation` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `saturation` - The new saturation, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_saturation(&mut self, saturation: T) -> Result<()> {
+        validate_unit_component(saturation, "saturation")?;
+        self.saturation = saturation;
+        Ok(())
+    }
+
+    /// Set the `lightness` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The new lightness, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_lightness(&mut self, lightness: T) -> Result<()> {
+        validate_unit_component(lightness, "lightness")?;
+        self.lightness = lightness;
+        Ok(())
+    }
+
+    /// Create an `HSLuv` colour from an XYZ colour.
+    /// Colours outside the sRGB gamut have their saturation clamped to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - The XYZ colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn from_xyz(xyz: &Xyz<T>) -> Result<Self> {
+        let [lightness, chroma, hue] = xyz_to_lch(xyz)?;
+
+        let saturation = if gamut::is_achromatic_lightness(lightness)? {
+            T::zero()
+        } else {
+            let max_chroma = gamut::max_chroma_for_lightness_hue(lightness, hue)?;
+            if max_chroma > T::zero() {
+                chroma / max_chroma
+            } else {
+                T::zero()
+            }
+        };
+
+        Self::new(
+            hue,
+            clamp_unit_component(saturation),
+            clamp_unit_component(lightness / safe_constant(100.0)?),
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for Hsluv<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_hsluv()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        Srgb::from_bytes(bytes)?.to_hsluv()
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_hsluv()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.hue, self.saturation, self.lightness]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [hue, saturation, lightness] = components;
+        Self::new(hue, saturation, lightness)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        // The hue is wrapped by `new`, so only the remaining components need clamping
+        let [hue, saturation, lightness] = components;
+        Self::new(hue, clamp_unit_component(saturation), clamp_unit_component(lightness))
+    }
+
+    /// Linear interpolate between two `HSLuv` colours.
+    /// This uses the shortest path around the hue circle for interpolation.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        // Take the shortest path around the hue circle
+        let f180 = safe_constant::<u32, T>(180)?;
+        let f360 = safe_constant::<u32, T>(360)?;
+        let mut hue_diff = rhs.hue - lhs.hue;
+        if hue_diff > f180 {
+            hue_diff = hue_diff - f360;
+        } else if hue_diff < -f180 {
+            hue_diff = hue_diff + f360;
+        }
+
+        let hue = lhs.hue + t * hue_diff;
+        if !hue.is_finite() {
+            return Err(InterpolationError::HueInterpolation {
+                hue1: lhs.hue.to_f64().unwrap_or(f64::NAN),
+                hue2: rhs.hue.to_f64().unwrap_or(f64::NAN),
+            }
+            .into());
+        }
+
+        let saturation = lhs.saturation * (T::one() - t) + rhs.saturation * t;
+        let lightness = lhs.lightness * (T::one() - t) + rhs.lightness * t;
+
+        Self::new(hue, saturation, lightness)
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Hsluv<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_hsluv()
+    }
+
+    fn to_hsluv(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        let lightness = self.lightness * safe_constant(100.0)?;
+
+        let chroma = if gamut::is_achromatic_lightness(lightness)? {
+            T::zero()
+        } else {
+            gamut::max_chroma_for_lightness_hue(lightness, self.hue)? * self.saturation
+        };
+
+        lch_to_xyz([lightness, chroma, self.hue])
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Hsluv<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_lightness_is_perceptually_constant_unlike_hsl() -> Result<()> {
+        let (mut hsluv_range, mut hsl_range) = ((f64::MAX, f64::MIN), (f64::MAX, f64::MIN));
+        for hue in [0.0, 60.0, 120.0, 180.0, 240.0, 300.0] {
+            let hsluv = Hsluv::<f64>::new(hue, 1.0, 0.5)?.to_lab()?.lightness();
+            let hsl = Hsl::<f64>::new(hue, 1.0, 0.5)?.to_srgb()?.to_lab()?.lightness();
+
+            hsluv_range = (hsluv_range.0.min(hsluv), hsluv_range.1.max(hsluv));
+            hsl_range = (hsl_range.0.min(hsl), hsl_range.1.max(hsl));
+        }
+
+        assert!(hsluv_range.1 - hsluv_range.0 < 0.5);
+        assert!(hsl_range.1 - hsl_range.0 > 30.0);
+        Ok(())
+    }
+
+    #[test]
+    fn red_matches_reference_hsluv() -> Result<()> {
+        let red = Srgb::<f64>::from_hex("#ff0000")?.to_hsluv()?;
+
+        assert!((red.hue() - 12.177).abs() < 0.05);
+        assert!((red.saturation() - 1.0).abs() < 5e-4);
+        assert!((red.lightness() - 0.532_37).abs() < 5e-4);
+        Ok(())
+    }
+}
@@ -1,16 +1,17 @@
 //! HSV (Hue, Saturation, Value) colour representation.
 
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
     error::{
-        InterpolationError, Result, format_terminal_color, normalize_hue, safe_constant, validate_interpolation_factor,
-        validate_unit_component,
+        InterpolationError, Result, clamp_unit_component, format_terminal_color, normalize_hue, safe_constant,
+        validate_interpolation_factor, validate_unit_component,
     },
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
 /// HSV colour representation.
@@ -129,6 +130,22 @@ impl<T: Float + Send + Sync> Hsv<T> {
         self.value = value;
         Ok(())
     }
+
+    /// Create a copy of the colour with its hue rotated, wrapping into [0, 360).
+    ///
+    /// # Arguments
+    ///
+    /// * `degrees` - The rotation in degrees, positive or negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rotated hue is not finite.
+    pub fn rotate_hue(&self, degrees: T) -> Result<Self> {
+        Ok(Self {
+            hue: normalize_hue(self.hue + degrees)?,
+            ..*self
+        })
+    }
 }
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Hsv<T> {
@@ -148,6 +165,29 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Hsv<T> {
         self.to_rgb()?.to_bytes()
     }
 
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Rgb::from_bytes16(bytes)?.to_hsv()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_rgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.hue, self.saturation, self.value]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [hue, saturation, value] = components;
+        Self::new(hue, saturation, value)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        // The hue is wrapped by `new`, so only the remaining components need clamping
+        let [hue, saturation, value] = components;
+        Self::new(hue, clamp_unit_component(saturation), clamp_unit_component(value))
+    }
+
     /// Linear interpolate between two HSV colours.
     /// This uses the shortest path around the hue circle for interpolation.
     fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
@@ -192,6 +232,12 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Hsv<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Hsv<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_hsv()
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
         Grey::new(self.value)
     }
@@ -201,28 +247,8 @@ impl<T: Float + Send + Sync> Convert<T> for Hsv<T> {
     }
 
     fn to_hsl(&self) -> Result<Hsl<T>> {
-        // Convert HSV to HSL
-        // Hue remains the same
-        let hue = self.hue;
-
-        // Calculate lightness: L = V * (2 - S) / 2
-        let lightness = self.value * (safe_constant::<f64, T>(2.0)? - self.saturation) / safe_constant(2.0)?;
-
-        // Calculate saturation for HSL
-        let saturation = if lightness.abs() < T::epsilon() || (lightness - T::one()).abs() < T::epsilon() {
-            // If lightness is 0 or 1, saturation is 0
-            T::zero()
-        } else {
-            // S_hsl = V * S / (1 - |2L - 1|)
-            let denominator = T::one() - (safe_constant::<f64, T>(2.0)? * lightness - T::one()).abs();
-            if denominator.abs() < T::epsilon() {
-                T::zero()
-            } else {
-                self.value * self.saturation / denominator
-            }
-        };
-
-        Hsl::new(hue, saturation, lightness)
+        // HSL is defined over gamma-encoded sRGB, so convert through it rather than between the cylinders directly
+        self.to_srgb()?.to_hsl()
     }
 
     fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
@@ -254,7 +280,8 @@ impl<T: Float + Send + Sync> Convert<T> for Hsv<T> {
         let v = self.value;
 
         // Handle achromatic case (no hue)
-        if s.abs() < T::epsilon() {
+        // Return the value directly, so achromatic colours round-trip exactly whatever the hue
+        if s == T::zero() {
             return Rgb::new(v, v, v);
         }
 
@@ -316,8 +343,42 @@ impl<T: Float + Send + Sync> Convert<T> for Hsv<T> {
 
 impl<T: Float + Send + Sync> Display for Hsv<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let rgb = self.to_rgb()?;
         let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn achromatic_rgb_round_trips_exactly() -> Result<()> {
+        for value in [0.0, 0.1, 1.0 / 3.0, 0.5, 0.737, 1.0] {
+            let grey = Rgb::<f64>::new(value, value, value)?;
+
+            let hsv = grey.to_hsv()?;
+            assert!(hsv.saturation().abs() < f64::EPSILON);
+            let round_trip = hsv.to_rgb()?;
+            assert_eq!(round_trip.components().map(f64::to_bits), grey.components().map(f64::to_bits));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rotate_hue_wraps_in_both_directions() -> Result<()> {
+        let red = Hsv::<f64>::new(0.0, 1.0, 1.0)?;
+
+        let green = red.rotate_hue(120.0)?.to_rgb()?;
+        assert!((green.green() - 1.0).abs() < 1e-12 && green.red().abs() < 1e-12 && green.blue().abs() < 1e-12);
+
+        assert!((Hsv::<f64>::new(350.0, 1.0, 1.0)?.rotate_hue(20.0)?.hue() - 10.0).abs() < 1e-9);
+        assert!((red.rotate_hue(-30.0)?.hue() - 330.0).abs() < 1e-9);
+        Ok(())
+    }
+}
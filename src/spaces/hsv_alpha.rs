@@ -1,13 +1,18 @@
 //! HSV colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     error::{Result, normalize_hue, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// HSV with alpha channel.
@@ -165,3 +170,4 @@ impl<T: Float + Send + Sync> HsvAlpha<T> {
 impl_transparent_colour!(HsvAlpha<T>, Hsv<T>, 3);
 impl_transparent_convert!(HsvAlpha<T>, Hsv<T>);
 impl_transparent_display!(HsvAlpha<T>);
+impl_transparent_alpha!(HsvAlpha<T>);
@@ -9,16 +9,23 @@
 //! Lab is designed to be perceptually uniform, meaning a change of the same amount in a value
 //! should produce a change of about the same visual importance.
 
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
-    error::{Result, format_terminal_color, safe_constant, validate_component_range, validate_interpolation_factor},
+    error::{
+        Result, clamp_component_range, format_terminal_color, normalize_hue, safe_constant, validate_component_range,
+        validate_interpolation_factor,
+    },
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
+/// Number of bisection steps used when searching for the sRGB gamut boundary.
+const GAMUT_SEARCH_ITERATIONS: usize = 32;
+
 /// LAB colour representation.
 #[derive(Debug, Clone, Copy)]
 pub struct Lab<T: Float + Send + Sync> {
@@ -73,6 +80,11 @@ impl<T: Float + Send + Sync> Lab<T> {
         self.b_star
     }
 
+    /// Get the chroma (colourfulness), the distance from the neutral axis `sqrt(a*² + b*²)`.
+    pub fn chroma(&self) -> T {
+        self.a_star.hypot(self.b_star)
+    }
+
     /// Set the `lightness` component with validation.
     ///
     /// # Arguments
@@ -123,6 +135,73 @@ impl<T: Float + Send + Sync> Lab<T> {
         Ok(())
     }
 
+    /// Set the chroma by scaling a* and b*, preserving lightness and hue angle.
+    ///
+    /// The scaled components are clamped to [-128, 127], which may shift the hue of very high chroma values.
+    /// A neutral colour has no hue, so its chroma is applied along the positive a* axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `chroma` - The new chroma, must be non-negative; 0 gives a neutral grey
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is negative.
+    pub fn set_chroma(&mut self, chroma: T) -> Result<()> {
+        validate_component_range(chroma, "chroma", T::zero(), T::infinity())?;
+
+        let (min_chroma, max_chroma) = (safe_constant(-128.0)?, safe_constant(127.0)?);
+        let (sin, cos) = self.b_star.atan2(self.a_star).sin_cos();
+        self.a_star = clamp_component_range(chroma * cos, min_chroma, max_chroma);
+        self.b_star = clamp_component_range(chroma * sin, min_chroma, max_chroma);
+        Ok(())
+    }
+
+    /// Return a copy with the chroma replaced, preserving lightness and hue angle.
+    ///
+    /// This is the non-mutating form of [`Lab::set_chroma`], which follows the in-place convention of the
+    /// other component setters.
+    ///
+    /// # Arguments
+    ///
+    /// * `chroma` - The new chroma, must be non-negative; 0 gives a neutral grey
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is negative.
+    pub fn with_chroma(&self, chroma: T) -> Result<Self> {
+        let mut colour = *self;
+        colour.set_chroma(chroma)?;
+        Ok(colour)
+    }
+
+    /// Increase the lightness (L*) by a fixed amount, clamping the result to [0, 100].
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The amount to add to L*
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn lighten(&self, delta: T) -> Result<Self> {
+        let lightness = clamp_component_range(self.lightness + delta, T::zero(), safe_constant(100.0)?);
+        Self::new(lightness, self.a_star, self.b_star)
+    }
+
+    /// Decrease the lightness (L*) by a fixed amount, clamping the result to [0, 100].
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The amount to subtract from L*
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn darken(&self, delta: T) -> Result<Self> {
+        self.lighten(-delta)
+    }
+
     /// Calculate perceptual colour difference in Lab space (CIE76 Delta E).
     /// The Delta E value indicates how different two colours appear, with values:
     /// - < 1.0: Not perceptible by human eyes
@@ -183,6 +262,199 @@ impl<T: Float + Send + Sync> Lab<T> {
 
         Ok((term1 + term2 + term3).sqrt())
     }
+
+    /// Calculate perceptual colour difference using the CIEDE2000 Delta E formula.
+    /// This is the most accurate of the Delta E formulas, correcting for perceptual non-uniformities
+    /// in lightness, chroma and hue, including the blue region rotation term.
+    /// Results agree with the published test data of Sharma, Wu and Dalal (2005) to four decimal places.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mathematical operations fail during calculation.
+    #[expect(
+        clippy::similar_names,
+        reason = "Primed and unprimed terms follow the naming of the published CIEDE2000 formula."
+    )]
+    pub fn delta_e2000(&self, other: &Self) -> Result<T> {
+        let two = safe_constant::<f64, T>(2.0)?;
+        let f180 = safe_constant::<f64, T>(180.0)?;
+        let f360 = safe_constant::<f64, T>(360.0)?;
+        let pow25_7 = safe_constant::<f64, T>(25.0_f64.powi(7))?;
+
+        // Chroma adjustment of a* for low chroma colours
+        let c1 = self.a_star.hypot(self.b_star);
+        let c2 = other.a_star.hypot(other.b_star);
+        let c_bar = (c1 + c2) / two;
+        let c_bar7 = c_bar.powi(7);
+        let g = safe_constant::<f64, T>(0.5)? * (T::one() - (c_bar7 / (c_bar7 + pow25_7)).sqrt());
+
+        let a1_prime = (T::one() + g) * self.a_star;
+        let a2_prime = (T::one() + g) * other.a_star;
+        let c1_prime = a1_prime.hypot(self.b_star);
+        let c2_prime = a2_prime.hypot(other.b_star);
+
+        let hue_angle = |b: T, a: T| -> Result<T> {
+            if b.is_zero() && a.is_zero() {
+                Ok(T::zero())
+            } else {
+                normalize_hue(b.atan2(a).to_degrees())
+            }
+        };
+        let h1_prime = hue_angle(self.b_star, a1_prime)?;
+        let h2_prime = hue_angle(other.b_star, a2_prime)?;
+
+        // Differences in lightness, chroma and hue
+        let delta_l_prime = other.lightness - self.lightness;
+        let delta_c_prime = c2_prime - c1_prime;
+
+        let chroma_product = c1_prime * c2_prime;
+        let delta_h_angle = if chroma_product.is_zero() {
+            T::zero()
+        } else {
+            let diff = h2_prime - h1_prime;
+            if diff > f180 {
+                diff - f360
+            } else if diff < -f180 {
+                diff + f360
+            } else {
+                diff
+            }
+        };
+        let delta_h_prime = two * chroma_product.sqrt() * (delta_h_angle / two).to_radians().sin();
+
+        // Mean lightness, chroma and hue
+        let l_bar_prime = (self.lightness + other.lightness) / two;
+        let c_bar_prime = (c1_prime + c2_prime) / two;
+        let h_sum = h1_prime + h2_prime;
+        let h_bar_prime = if chroma_product.is_zero() {
+            h_sum
+        } else if (h1_prime - h2_prime).abs() <= f180 {
+            h_sum / two
+        } else if h_sum < f360 {
+            (h_sum + f360) / two
+        } else {
+            (h_sum - f360) / two
+        };
+
+        // Weighting functions
+        let t = T::one() - safe_constant::<f64, T>(0.17)? * (h_bar_prime - safe_constant(30.0)?).to_radians().cos()
+            + safe_constant::<f64, T>(0.24)? * (two * h_bar_prime).to_radians().cos()
+            + safe_constant::<f64, T>(0.32)?
+                * (safe_constant::<f64, T>(3.0)? * h_bar_prime + safe_constant(6.0)?)
+                    .to_radians()
+                    .cos()
+            - safe_constant::<f64, T>(0.20)?
+                * (safe_constant::<f64, T>(4.0)? * h_bar_prime - safe_constant(63.0)?)
+                    .to_radians()
+                    .cos();
+
+        let l_offset = (l_bar_prime - safe_constant(50.0)?).powi(2);
+        let s_l = T::one() + safe_constant::<f64, T>(0.015)? * l_offset / (safe_constant::<f64, T>(20.0)? + l_offset).sqrt();
+        let s_c = T::one() + safe_constant::<f64, T>(0.045)? * c_bar_prime;
+        let s_h = T::one() + safe_constant::<f64, T>(0.015)? * c_bar_prime * t;
+
+        // Rotation term for the blue region
+        let delta_theta =
+            safe_constant::<f64, T>(30.0)? * (-((h_bar_prime - safe_constant(275.0)?) / safe_constant(25.0)?).powi(2)).exp();
+        let c_bar_prime7 = c_bar_prime.powi(7);
+        let r_c = two * (c_bar_prime7 / (c_bar_prime7 + pow25_7)).sqrt();
+        let r_t = -(two * delta_theta).to_radians().sin() * r_c;
+
+        // Combine the terms
+        let term_l = delta_l_prime / s_l;
+        let term_c = delta_c_prime / s_c;
+        let term_h = delta_h_prime / s_h;
+
+        Ok((term_l * term_l + term_c * term_c + term_h * term_h + r_t * term_c * term_h).sqrt())
+    }
+
+    /// Check whether the colour lies within the sRGB gamut.
+    ///
+    /// Colours whose XYZ representation is outside the valid XYZ range are outside the gamut.
+    #[must_use]
+    pub fn in_srgb_gamut(&self) -> bool {
+        self.to_xyz().is_ok_and(|xyz| xyz.in_srgb_gamut())
+    }
+
+    /// Map the colour into the sRGB gamut by reducing its chroma, preserving lightness and hue.
+    ///
+    /// The chroma is binary-searched towards the neutral axis, so the result is the most saturated in-gamut
+    /// colour of the same lightness and hue, rather than the hue shift produced by clamping each RGB channel.
+    /// Colours already inside the gamut are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn map_to_srgb_gamut(&self) -> Result<Self> {
+        if self.in_srgb_gamut() {
+            return Ok(*self);
+        }
+
+        // Binary search for the largest chroma scale that is inside the gamut
+        let half = safe_constant::<f64, T>(0.5)?;
+        let (mut low, mut high) = (T::zero(), T::one());
+        for _ in 0..GAMUT_SEARCH_ITERATIONS {
+            let scale = (low + high) * half;
+            if Self::new(self.lightness, self.a_star * scale, self.b_star * scale)?.in_srgb_gamut() {
+                low = scale;
+            } else {
+                high = scale;
+            }
+        }
+
+        Self::new(self.lightness, self.a_star * low, self.b_star * low)
+    }
+
+    /// Convert to XYZ relative to the given reference white.
+    ///
+    /// [`Convert::to_xyz`] is equivalent to passing the D65 reference white.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - The reference white that this colour's lightness and opponent axes are relative to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting XYZ components are invalid.
+    pub fn to_xyz_with_white(&self, white: &Xyz<T>) -> Result<Xyz<T>> {
+        // Constants for the conversion
+        let epsilon = safe_constant(0.008_856)?; // Intent is 216/24389
+        let kappa = safe_constant(903.3)?; // Intent is 24389/27
+
+        // Compute f_y
+        let l = self.lightness;
+        let f_y = (l + safe_constant(16.0)?) / safe_constant(116.0)?;
+
+        // Compute f_x and f_z using a and b
+        let f_x = self.a_star / safe_constant(500.0)? + f_y;
+        let f_z = f_y - self.b_star / safe_constant(200.0)?;
+
+        // Convert f values to XYZ coordinates
+        let x_r = if f_x.powi(3) > epsilon {
+            f_x.powi(3)
+        } else {
+            (f_x * safe_constant(116.0)? - safe_constant(16.0)?) / kappa
+        };
+
+        let y_r = if l > safe_constant(8.0)? {
+            ((l + safe_constant(16.0)?) / safe_constant(116.0)?).powi(3)
+        } else {
+            l / kappa
+        };
+
+        let z_r = if f_z.powi(3) > epsilon {
+            f_z.powi(3)
+        } else {
+            (f_z * safe_constant(116.0)? - safe_constant(16.0)?) / kappa
+        };
+
+        // Scale by reference white
+        let x = x_r * white.x();
+        let y = y_r * white.y();
+        let z = z_r * white.z();
+
+        Xyz::new(x, y, z)
+    }
 }
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Lab<T> {
@@ -207,6 +479,34 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Lab<T> {
         self.to_srgb()?.to_bytes()
     }
 
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_lab()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.lightness, self.a_star, self.b_star]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [lightness, a_star, b_star] = components;
+        Self::new(lightness, a_star, b_star)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [lightness, a_star, b_star] = components;
+        let (min_chroma, max_chroma) = (safe_constant(-128.0)?, safe_constant(127.0)?);
+
+        Self::new(
+            clamp_component_range(lightness, T::zero(), safe_constant(100.0)?),
+            clamp_component_range(a_star, min_chroma, max_chroma),
+            clamp_component_range(b_star, min_chroma, max_chroma),
+        )
+    }
+
     /// Linear interpolate between two Lab colours.
     ///
     /// Lab is designed to be perceptually uniform, so linear interpolation
@@ -223,6 +523,16 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Lab<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Lab<T> {
+    const GAMUT: Gamut = Gamut::Visible;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_lab()
+    }
+
+    fn from_lab(colour: &Self) -> Result<Self> {
+        Ok(*colour)
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
         // For greyscale, we should just use the L component (lightness)
         // We need to normalize from [0, 100] to [0, 1]
@@ -283,46 +593,7 @@ impl<T: Float + Send + Sync> Convert<T> for Lab<T> {
     }
 
     fn to_xyz(&self) -> Result<Xyz<T>> {
-        // Constants for the conversion
-        let epsilon = safe_constant(0.008_856)?; // Intent is 216/24389
-        let kappa = safe_constant(903.3)?; // Intent is 24389/27
-
-        // D65 reference white
-        let ref_white = Xyz::<T>::d65_reference_white()?;
-
-        // Compute f_y
-        let l = self.lightness;
-        let f_y = (l + safe_constant(16.0)?) / safe_constant(116.0)?;
-
-        // Compute f_x and f_z using a and b
-        let f_x = self.a_star / safe_constant(500.0)? + f_y;
-        let f_z = f_y - self.b_star / safe_constant(200.0)?;
-
-        // Convert f values to XYZ coordinates
-        let x_r = if f_x.powi(3) > epsilon {
-            f_x.powi(3)
-        } else {
-            (f_x * safe_constant(116.0)? - safe_constant(16.0)?) / kappa
-        };
-
-        let y_r = if l > safe_constant(8.0)? {
-            ((l + safe_constant(16.0)?) / safe_constant(116.0)?).powi(3)
-        } else {
-            l / kappa
-        };
-
-        let z_r = if f_z.powi(3) > epsilon {
-            f_z.powi(3)
-        } else {
-            (f_z * safe_constant(116.0)? - safe_constant(16.0)?) / kappa
-        };
-
-        // Scale by reference white
-        let x = x_r * ref_white.x();
-        let y = y_r * ref_white.y();
-        let z = z_r * ref_white.z();
-
-        Xyz::new(x, y, z)
+        self.to_xyz_with_white(&Xyz::d65_reference_white()?)
     }
 
     fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
@@ -333,8 +604,171 @@ impl<T: Float + Send + Sync> Convert<T> for Lab<T> {
 
 impl<T: Float + Send + Sync> Display for Lab<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let rgb = self.to_rgb()?;
         let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// CIEDE2000 test data from Sharma, Wu & Dalal (2005): (L1, a1, b1, L2, a2, b2, ΔE00).
+    const SHARMA_PAIRS: [[f64; 7]; 34] = [
+        [50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425],
+        [50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615],
+        [50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412],
+        [50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669],
+        [50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461],
+        [50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065],
+        [50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492],
+        [50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977],
+        [50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030],
+        [50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000],
+        [60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644],
+        [63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630],
+        [61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731],
+        [35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645],
+        [22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373],
+        [36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146],
+        [90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441],
+        [90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381],
+        [6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377],
+        [2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082],
+    ];
+
+    #[test]
+    fn map_to_srgb_gamut_reduces_chroma_at_fixed_lightness() -> Result<()> {
+        let colour = Lab::<f64>::new(50.0, 90.0, -90.0)?;
+        assert!(!colour.in_srgb_gamut());
+
+        let mapped = colour.map_to_srgb_gamut()?;
+        assert!(mapped.in_srgb_gamut());
+        assert!((mapped.lightness() - colour.lightness()).abs() < 1e-12);
+        assert!(mapped.chroma() < colour.chroma());
+        assert!((mapped.a_star() / mapped.b_star() - colour.a_star() / colour.b_star()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn map_to_srgb_gamut_leaves_in_gamut_colours_unchanged() -> Result<()> {
+        let colour = Lab::<f64>::new(50.0, 10.0, -10.0)?;
+        let mapped = colour.map_to_srgb_gamut()?;
+
+        assert!((mapped.a_star() - colour.a_star()).abs() < 1e-12);
+        assert!((mapped.b_star() - colour.b_star()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn with_chroma_zero_gives_neutral_grey() -> Result<()> {
+        let colour = Lab::<f64>::new(60.0, 40.0, -30.0)?;
+
+        let grey = colour.with_chroma(0.0)?;
+        assert!(grey.a_star().abs() < 1e-12);
+        assert!(grey.b_star().abs() < 1e-12);
+        assert!((grey.lightness() - 60.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn with_chroma_preserves_hue_angle() -> Result<()> {
+        let colour = Lab::<f64>::new(60.0, 30.0, 40.0)?;
+
+        let scaled = colour.with_chroma(25.0)?;
+        assert!((scaled.chroma() - 25.0).abs() < 1e-12);
+        assert!((scaled.a_star() - 15.0).abs() < 1e-12);
+        assert!((scaled.b_star() - 20.0).abs() < 1e-12);
+        assert!((colour.chroma() - 50.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn delta_e2000_matches_sharma_test_data() -> Result<()> {
+        for [l1, a1, b1, l2, a2, b2, expected] in SHARMA_PAIRS {
+            let lhs = Lab::<f64>::new(l1, a1, b1)?;
+            let rhs = Lab::<f64>::new(l2, a2, b2)?;
+
+            let forward = lhs.delta_e2000(&rhs)?;
+            let backward = rhs.delta_e2000(&lhs)?;
+            assert!(
+                (forward - expected).abs() < 1e-4,
+                "{lhs:?} vs {rhs:?}: {forward} != {expected}"
+            );
+            assert!(
+                (backward - expected).abs() < 1e-4,
+                "{rhs:?} vs {lhs:?}: {backward} != {expected}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mid_grey_round_trips_through_d50_white() -> Result<()> {
+        let d50 = Xyz::<f64>::d50_reference_white()?;
+        let grey = Lab::<f64>::new(50.0, 0.0, 0.0)?;
+
+        let xyz = grey.to_xyz_with_white(&d50)?;
+        // A neutral colour scales the reference white by its relative luminance
+        assert!((xyz.x() / d50.x() - xyz.y() / d50.y()).abs() < 1e-12);
+
+        let restored = xyz.to_lab_with_white(&d50)?;
+        for (lhs, rhs) in restored.components().iter().zip(grey.components()) {
+            assert!((lhs - rhs).abs() < 1e-9);
+        }
+
+        // Converting with a different white changes the result, so the white is honoured
+        let d65 = grey.to_xyz()?;
+        assert!((d65.x() - xyz.x()).abs() > 1e-3);
+        Ok(())
+    }
+
+    #[test]
+    fn saturated_lab_is_outside_srgb_gamut() -> Result<()> {
+        assert!(!Lab::<f64>::new(50.0, 100.0, 0.0)?.in_srgb_gamut());
+        assert!(Lab::<f64>::new(50.0, 0.0, 0.0)?.in_srgb_gamut());
+        assert!(Srgb::<f64>::new(1.0, 0.0, 0.0)?.to_lab()?.in_srgb_gamut());
+        Ok(())
+    }
+
+    #[test]
+    fn lighten_and_darken_shift_lightness_within_range() -> Result<()> {
+        let grey = Lab::<f64>::new(50.0, 0.0, 0.0)?;
+
+        assert_eq!(grey.lighten(10.0)?.lightness().to_bits(), 60.0_f64.to_bits());
+        assert_eq!(grey.darken(10.0)?.lightness().to_bits(), 40.0_f64.to_bits());
+        assert_eq!(grey.lighten(80.0)?.lightness().to_bits(), 100.0_f64.to_bits());
+        assert_eq!(grey.darken(80.0)?.lightness().to_bits(), 0.0_f64.to_bits());
+        Ok(())
+    }
+
+    #[test]
+    fn rgb_lighten_round_trips_through_lab() -> Result<()> {
+        let grey = Lab::<f64>::new(50.0, 0.0, 0.0)?.to_rgb()?;
+
+        let lighter = grey.lighten(10.0)?.to_lab()?;
+        assert!((lighter.lightness() - 60.0).abs() < 1e-4);
+        let darker = grey.darken(10.0)?.to_lab()?;
+        assert!((darker.lightness() - 40.0).abs() < 1e-4);
+        Ok(())
+    }
+}
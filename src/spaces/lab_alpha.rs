@@ -1,13 +1,18 @@
 //! Lab colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     error::{Result, safe_constant, validate_component_range, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// Lab with alpha channel.
@@ -184,3 +189,4 @@ impl<T: Float + Send + Sync> LabAlpha<T> {
 impl_transparent_colour!(LabAlpha<T>, Lab<T>, 3);
 impl_transparent_convert!(LabAlpha<T>, Lab<T>);
 impl_transparent_display!(LabAlpha<T>);
+impl_transparent_alpha!(LabAlpha<T>);
@@ -0,0 +1,224 @@
+//! Linear RGB colour representation with linear byte encoding.
+//!
+//! `LinearRgb` holds the same linear-light components as `Rgb`, but its hex strings and byte arrays quantise those
+//! components directly, as linear textures and framebuffers do, rather than sRGB-encoding them first.
+//! So `LinearRgb` and `Rgb` agree on every conversion, and differ only in how they are written out as integers:
+//! linear 0.5 is `#808080` as a `LinearRgb` but `#BCBCBC` as an `Rgb`.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{Result, clamp_unit_component, format_terminal_color, validate_interpolation_factor, validate_unit_component},
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// Linear RGB colour representation whose integer encodings are linear too.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearRgb<T: Float + Send + Sync> {
+    /// Linear red component in range [0, 1].
+    red: T,
+    /// Linear green component in range [0, 1].
+    green: T,
+    /// Linear blue component in range [0, 1].
+    blue: T,
+}
+
+impl<T: Float + Send + Sync> LinearRgb<T> {
+    /// Create a new `LinearRgb` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The linear red component, must be in range [0, 1]
+    /// * `green` - The linear green component, must be in range [0, 1]
+    /// * `blue` - The linear blue component, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn new(red: T, green: T, blue: T) -> Result<Self> {
+        validate_unit_component(red, "red")?;
+        validate_unit_component(green, "green")?;
+        validate_unit_component(blue, "blue")?;
+
+        Ok(Self { red, green, blue })
+    }
+
+    /// Get the `red` component.
+    pub const fn red(&self) -> T {
+        self.red
+    }
+
+    /// Get the `green` component.
+    pub const fn green(&self) -> T {
+        self.green
+    }
+
+    /// Get the `blue` component.
+    pub const fn blue(&self) -> T {
+        self.blue
+    }
+}
+
+impl<T: Float + Send + Sync> From<Rgb<T>> for LinearRgb<T> {
+    fn from(colour: Rgb<T>) -> Self {
+        Self {
+            red: colour.red(),
+            green: colour.green(),
+            blue: colour.blue(),
+        }
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for LinearRgb<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        // Hex digits encode the linear components directly
+        let [red, green, blue] = Srgb::from_hex(hex)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        Srgb::new(self.red, self.green, self.blue)?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        let [red, green, blue] = Srgb::from_bytes(bytes)?.components();
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        Srgb::new(self.red, self.green, self.blue)?.to_bytes()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components;
+        Self::new(red, green, blue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components.map(clamp_unit_component);
+        Self::new(red, green, blue)
+    }
+
+    /// Linear interpolate between two linear RGB colours, directly in linear light.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.red * (T::one() - t) + rhs.red * t,
+            lhs.green * (T::one() - t) + rhs.green * t,
+            lhs.blue * (T::one() - t) + rhs.blue * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for LinearRgb<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        Self::new(colour.red(), colour.green(), colour.blue())
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_rgb()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        self.to_rgb()?.to_grey_alpha()
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        self.to_rgb()?.to_hsl_alpha()
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        self.to_rgb()?.to_hsv_alpha()
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_rgb()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        self.to_rgb()?.to_lab_alpha()
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        RgbAlpha::new(self.red, self.green, self.blue, T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_rgb()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        self.to_rgb()?.to_srgb_alpha()
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        self.to_rgb()?.to_xyz()
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        self.to_rgb()?.to_xyz_alpha()
+    }
+}
+
+impl<T: Float + Send + Sync> Display for LinearRgb<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::GammaPrecision;
+
+    #[test]
+    fn to_srgb_applies_gamma_encoding() -> Result<()> {
+        let linear = LinearRgb::<f64>::new(0.5, 0.0, 1.0)?;
+        let encoded = linear.to_srgb()?;
+
+        assert!((encoded.red() - Srgb::gamma_encode_with(0.5, GammaPrecision::Exact)?).abs() < 1e-4);
+        assert!(encoded.green().abs() < 1e-12);
+        assert!((encoded.blue() - 1.0).abs() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn hex_encodes_linear_components_directly() -> Result<()> {
+        let linear = LinearRgb::<f64>::from_hex("#808080")?;
+
+        assert!((linear.red() - 128.0 / 255.0).abs() < 1e-12);
+        assert_eq!(linear.to_hex()?, "#808080");
+        assert_eq!(Rgb::from(linear).to_hex()?, "#BCBCBC");
+        assert_eq!(LinearRgb::from(Rgb::<f64>::from_hex("#BCBCBC")?).to_bytes()?, [128, 128, 128]);
+        Ok(())
+    }
+}
@@ -0,0 +1,343 @@
+//! `LCh(uv)` colour representation.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        InterpolationError, Result, clamp_component_range, format_terminal_color, normalize_hue, safe_constant,
+        validate_component_range, validate_interpolation_factor,
+    },
+    spaces::{
+        Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Luv, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha,
+    },
+    traits::{Colour, Convert, Gamut},
+};
+
+/// `LCh(uv)` colour representation, the cylindrical form of CIE L*u*v*.
+#[derive(Debug, Clone, Copy)]
+pub struct Lchuv<T: Float + Send + Sync> {
+    /// Lightness component in range [0, 100].
+    lightness: T,
+    /// Chroma component in range [0, 200].
+    chroma: T,
+    /// Hue component in degrees [0, 360).
+    hue: T,
+}
+
+impl<T: Float + Send + Sync> Lchuv<T> {
+    /// Create a new `Lchuv` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The L* component, must be in range [0, 100]
+    /// * `chroma` - The chroma, must be in range [0, 200]
+    /// * `hue` - The hue in degrees, will be normalized to [0, 360)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if lightness is outside [0, 100], if chroma is outside [0, 200],
+    /// or if hue normalization fails.
+    pub fn new(lightness: T, chroma: T, hue: T) -> Result<Self> {
+        validate_component_range(lightness, "lightness", T::zero(), safe_constant(100.0)?)?;
+        validate_component_range(chroma, "chroma", T::zero(), safe_constant(super::MAX_UV)?)?;
+
+        Ok(Self {
+            lightness,
+            chroma,
+            hue: normalize_hue(hue)?,
+        })
+    }
+
+    /// Get the `lightness` component (L*).
+    pub const fn lightness(&self) -> T {
+        self.lightness
+    }
+
+    /// Get the `chroma` component.
+    pub const fn chroma(&self) -> T {
+        self.chroma
+    }
+
+    /// Get the `hue` component in degrees [0, 360).
+    pub const fn hue(&self) -> T {
+        self.hue
+    }
+
+    /// Set the `lightness` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The new L* value, must be in range [0, 100]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 100].
+    pub fn set_lightness(&mut self, lightness: T) -> Result<()> {
+        validate_component_range(lightness, "lightness", T::zero(), safe_constant(100.0)?)?;
+        self.lightness = lightness;
+        Ok(())
+    }
+
+    /// Set the `chroma` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `chroma` - The new chroma, must be in range [0, 200]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 200].
+    pub fn set_chroma(&mut self, chroma: T) -> Result<()> {
+        validate_component_range(chroma, "chroma", T::zero(), safe_constant(super::MAX_UV)?)?;
+        self.chroma = chroma;
+        Ok(())
+    }
+
+    /// Return a copy with the chroma replaced, preserving lightness and hue.
+    ///
+    /// This is the non-mutating form of [`Lchuv::set_chroma`], which follows the in-place convention of the
+    /// other component setters.
+    ///
+    /// # Arguments
+    ///
+    /// * `chroma` - The new chroma, must be in range [0, 200]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 200].
+    pub fn with_chroma(&self, chroma: T) -> Result<Self> {
+        let mut colour = *self;
+        colour.set_chroma(chroma)?;
+        Ok(colour)
+    }
+
+    /// Set the `hue` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The new hue in degrees, will be normalized to [0, 360)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if hue normalization fails.
+    pub fn set_hue(&mut self, hue: T) -> Result<()> {
+        self.hue = normalize_hue(hue)?;
+        Ok(())
+    }
+
+    /// Create an `Lchuv` colour from a `Luv` colour.
+    /// Achromatic colours are given a hue of 0, and chroma beyond 200 (only reachable in the corners of the
+    /// u*v* range) is clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `luv` - The `Luv` colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if hue normalization or constant conversion fails.
+    pub fn from_luv(luv: &Luv<T>) -> Result<Self> {
+        let chroma = luv.u_star().hypot(luv.v_star());
+        Self::new(
+            luv.lightness(),
+            clamp_component_range(chroma, T::zero(), safe_constant(super::MAX_UV)?),
+            luv.v_star().atan2(luv.u_star()).to_degrees(),
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for Lchuv<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_lchuv()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        Srgb::from_bytes(bytes)?.to_lchuv()
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_lchuv()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.lightness, self.chroma, self.hue]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [lightness, chroma, hue] = components;
+        Self::new(lightness, chroma, hue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        // The hue is wrapped by `new`, so only the remaining components need clamping
+        let [lightness, chroma, hue] = components;
+        Self::new(
+            clamp_component_range(lightness, T::zero(), safe_constant(100.0)?),
+            clamp_component_range(chroma, T::zero(), safe_constant(super::MAX_UV)?),
+            hue,
+        )
+    }
+
+    /// Linear interpolate between two `LCh(uv)` colours.
+    /// This uses the shortest path around the hue circle for interpolation.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        // Take the shortest path around the hue circle
+        let f180 = safe_constant::<u32, T>(180)?;
+        let f360 = safe_constant::<u32, T>(360)?;
+        let mut hue_diff = rhs.hue - lhs.hue;
+        if hue_diff > f180 {
+            hue_diff = hue_diff - f360;
+        } else if hue_diff < -f180 {
+            hue_diff = hue_diff + f360;
+        }
+
+        let hue = lhs.hue + t * hue_diff;
+        if !hue.is_finite() {
+            return Err(InterpolationError::HueInterpolation {
+                hue1: lhs.hue.to_f64().unwrap_or(f64::NAN),
+                hue2: rhs.hue.to_f64().unwrap_or(f64::NAN),
+            }
+            .into());
+        }
+
+        let lightness = lhs.lightness * (T::one() - t) + rhs.lightness * t;
+        let chroma = lhs.chroma * (T::one() - t) + rhs.chroma * t;
+
+        Self::new(lightness, chroma, hue)
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Lchuv<T> {
+    const GAMUT: Gamut = Gamut::Visible;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_lchuv()
+    }
+
+    fn to_lchuv(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_luv(&self) -> Result<Luv<T>> {
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        Luv::new(self.lightness, self.chroma * cos, self.chroma * sin)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        self.to_luv()?.to_xyz()
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Lchuv<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_chroma_replaces_chroma_only() -> Result<()> {
+        let colour = Lchuv::<f64>::new(60.0, 40.0, 120.0)?;
+
+        let grey = colour.with_chroma(0.0)?;
+        assert!(grey.chroma().abs() < 1e-12);
+        assert!((grey.lightness() - 60.0).abs() < 1e-12);
+        assert!((grey.hue() - 120.0).abs() < 1e-12);
+        assert!((colour.chroma() - 40.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn with_chroma_rejects_out_of_range_values() -> Result<()> {
+        let colour = Lchuv::<f64>::new(60.0, 40.0, 120.0)?;
+
+        assert!(colour.with_chroma(-1.0).is_err());
+        assert!(colour.with_chroma(250.0).is_err());
+        Ok(())
+    }
+}
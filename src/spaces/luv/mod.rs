@@ -0,0 +1,338 @@
+//! CIE L*u*v* and `LCh(uv)` colour representations.
+//!
+//! CIE L*u*v* (CIELUV) is a perceptual colour space based on the u'v' chromaticity diagram, with three values:
+//! - L* for perceptual lightness (0 to 100)
+//! - u* from green (-) to red (+)
+//! - v* from blue (-) to yellow (+)
+//!
+//! `LCh(uv)` expresses the same space in cylindrical coordinates, with chroma and hue replacing u* and v*.
+//! Both use the D65 reference white.
+
+mod lchuv;
+pub(super) mod transform;
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        Result, clamp_component_range, format_terminal_color, safe_constant, validate_component_range,
+        validate_interpolation_factor,
+    },
+    spaces::{
+        Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha,
+        luv::transform::{luv_to_xyz, xyz_to_luv},
+    },
+    traits::{Colour, Convert, Gamut},
+};
+
+pub use lchuv::Lchuv;
+
+/// Largest magnitude accepted for the u* and v* components, covering the sRGB and Adobe RGB (1998) gamuts.
+const MAX_UV: f64 = 200.0;
+
+/// CIE L*u*v* colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct Luv<T: Float + Send + Sync> {
+    /// Lightness component in range [0, 100].
+    lightness: T,
+    /// u* component in range [-200, 200].
+    u_star: T,
+    /// v* component in range [-200, 200].
+    v_star: T,
+}
+
+impl<T: Float + Send + Sync> Luv<T> {
+    /// Create a new `Luv` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The L* component, must be in range [0, 100]
+    /// * `u_star` - The u* component, must be in range [-200, 200]
+    /// * `v_star` - The v* component, must be in range [-200, 200]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if lightness is outside [0, 100] or if u*/v* are outside [-200, 200].
+    pub fn new(lightness: T, u_star: T, v_star: T) -> Result<Self> {
+        let max_uv = safe_constant::<f64, T>(MAX_UV)?;
+
+        validate_component_range(lightness, "lightness", T::zero(), safe_constant(100.0)?)?;
+        validate_component_range(u_star, "u*", -max_uv, max_uv)?;
+        validate_component_range(v_star, "v*", -max_uv, max_uv)?;
+
+        Ok(Self {
+            lightness,
+            u_star,
+            v_star,
+        })
+    }
+
+    /// Get the `lightness` component (L*).
+    pub const fn lightness(&self) -> T {
+        self.lightness
+    }
+
+    /// Get the `u_star` component (u*).
+    pub const fn u_star(&self) -> T {
+        self.u_star
+    }
+
+    /// Get the `v_star` component (v*).
+    pub const fn v_star(&self) -> T {
+        self.v_star
+    }
+
+    /// Set the `lightness` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The new L* value, must be in range [0, 100]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 100].
+    pub fn set_lightness(&mut self, lightness: T) -> Result<()> {
+        validate_component_range(lightness, "lightness", T::zero(), safe_constant(100.0)?)?;
+        self.lightness = lightness;
+        Ok(())
+    }
+
+    /// Set the `u_star` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `u_star` - The new u* value, must be in range [-200, 200]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [-200, 200].
+    pub fn set_u_star(&mut self, u_star: T) -> Result<()> {
+        let max_uv = safe_constant::<f64, T>(MAX_UV)?;
+        validate_component_range(u_star, "u*", -max_uv, max_uv)?;
+        self.u_star = u_star;
+        Ok(())
+    }
+
+    /// Set the `v_star` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `v_star` - The new v* value, must be in range [-200, 200]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [-200, 200].
+    pub fn set_v_star(&mut self, v_star: T) -> Result<()> {
+        let max_uv = safe_constant::<f64, T>(MAX_UV)?;
+        validate_component_range(v_star, "v*", -max_uv, max_uv)?;
+        self.v_star = v_star;
+        Ok(())
+    }
+
+    /// Create a `Luv` colour from an XYZ colour, using the u'v' chromaticity of the D65 reference white.
+    ///
+    /// Black, and any colour whose X + 15Y + 3Z is zero, maps to L* = u* = v* = 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - The XYZ colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if u* or v* fall outside [-200, 200], or if constant conversion fails.
+    pub fn from_xyz(xyz: &Xyz<T>) -> Result<Self> {
+        let [lightness, u_star, v_star] = xyz_to_luv(xyz)?;
+        Self::new(lightness, u_star, v_star)
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for Luv<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_luv()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        Srgb::from_bytes(bytes)?.to_luv()
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_luv()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.lightness, self.u_star, self.v_star]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [lightness, u_star, v_star] = components;
+        Self::new(lightness, u_star, v_star)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [lightness, u_star, v_star] = components;
+        let max_uv = safe_constant::<f64, T>(MAX_UV)?;
+
+        Self::new(
+            clamp_component_range(lightness, T::zero(), safe_constant(100.0)?),
+            clamp_component_range(u_star, -max_uv, max_uv),
+            clamp_component_range(v_star, -max_uv, max_uv),
+        )
+    }
+
+    /// Linear interpolate between two Luv colours.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.lightness * (T::one() - t) + rhs.lightness * t,
+            lhs.u_star * (T::one() - t) + rhs.u_star * t,
+            lhs.v_star * (T::one() - t) + rhs.v_star * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Luv<T> {
+    const GAMUT: Gamut = Gamut::Visible;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_luv()
+    }
+
+    fn to_luv(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_lchuv(&self) -> Result<Lchuv<T>> {
+        Lchuv::from_luv(self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        luv_to_xyz(self.components())
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Luv<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neutral_axis_has_zero_chromaticity() -> Result<()> {
+        let white = Xyz::<f64>::d65_reference_white()?;
+        let grey = Luv::from_xyz(&Xyz::new(white.x() * 0.2, white.y() * 0.2, white.z() * 0.2)?)?;
+        assert!(grey.u_star().abs() < 1e-9 && grey.v_star().abs() < 1e-9);
+
+        // The RGB matrix is rounded, so RGB greys sit a hair off the neutral axis
+        let grey = Rgb::<f64>::new(0.5, 0.5, 0.5)?;
+        assert!(grey.to_luv()?.u_star().abs() < 1e-4 && grey.to_luv()?.v_star().abs() < 1e-4);
+        assert!(grey.to_lchuv()?.chroma() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn black_guards_the_chromaticity_division() -> Result<()> {
+        let black = Luv::from_xyz(&Xyz::<f64>::new(0.0, 0.0, 0.0)?)?;
+
+        assert_eq!(black.components().map(f64::to_bits), [0.0; 3].map(f64::to_bits));
+        Ok(())
+    }
+
+    #[test]
+    fn red_matches_reference_values() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?.to_luv()?;
+
+        for (value, expected) in red.components().iter().zip([53.24, 175.01, 37.76]) {
+            assert!((value - expected).abs() < 0.05);
+        }
+        let restored = red.to_rgb()?;
+        for (value, expected) in restored.components().iter().zip([1.0, 0.0, 0.0]) {
+            assert!((value - expected).abs() < 1e-6);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! CIE L*u*v* conversion maths shared by `Luv`, `Lchuv` and the `HSLuv` family of colour spaces.
+//!
+//! Lightness is in the CIE range [0, 100], chroma is unbounded and hue is in degrees [0, 360).
+
+use num_traits::Float;
+
+use crate::{
+    error::{Result, normalize_hue, safe_constant},
+    spaces::Xyz,
+    traits::Colour,
+};
+
+/// CIE constant kappa (24389/27).
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// CIE constant epsilon (216/24389).
+const EPSILON: f64 = 216.0 / 24389.0;
+
+/// Convert an XYZ colour to CIE L*u*v* components `[lightness, u, v]`.
+/// Black, including any colour with X + 15Y + 3Z of zero, maps to the origin.
+pub(in crate::spaces) fn xyz_to_luv<T: Float + Send + Sync>(xyz: &Xyz<T>) -> Result<[T; 3]> {
+    let [x, y, z] = xyz.components();
+    let (white_u, white_v) = white_chromaticity()?;
+
+    let denominator = x + safe_constant::<f64, T>(15.0)? * y + safe_constant::<f64, T>(3.0)? * z;
+    let lightness = y_to_lightness(y)?;
+    if denominator <= T::zero() || lightness <= T::zero() {
+        return Ok([T::zero(); 3]);
+    }
+
+    let scale = safe_constant::<f64, T>(13.0)? * lightness;
+    let u = scale * (safe_constant::<f64, T>(4.0)? * x / denominator - white_u);
+    let v = scale * (safe_constant::<f64, T>(9.0)? * y / denominator - white_v);
+    Ok([lightness, u, v])
+}
+
+/// Convert CIE L*u*v* components `[lightness, u, v]` to an XYZ colour.
+/// Results that fall marginally outside the valid XYZ ranges through rounding are clamped.
+pub(in crate::spaces) fn luv_to_xyz<T: Float + Send + Sync>(luv: [T; 3]) -> Result<Xyz<T>> {
+    let [lightness, u, v] = luv;
+    if lightness <= T::zero() {
+        return Xyz::new(T::zero(), T::zero(), T::zero());
+    }
+
+    let (white_u, white_v) = white_chromaticity()?;
+    let scale = safe_constant::<f64, T>(13.0)? * lightness;
+    let u_prime = u / scale + white_u;
+    let v_prime = v / scale + white_v;
+
+    let y = lightness_to_y(lightness)?;
+    let four_v = safe_constant::<f64, T>(4.0)? * v_prime;
+    let x = y * safe_constant::<f64, T>(9.0)? * u_prime / four_v;
+    let z = y
+        * (safe_constant::<f64, T>(12.0)? - safe_constant::<f64, T>(3.0)? * u_prime - safe_constant::<f64, T>(20.0)? * v_prime)
+        / four_v;
+
+    Xyz::from_components_clamped([x, y, z])
+}
+
+/// Convert an XYZ colour to cylindrical `LCh(uv)` components `[lightness, chroma, hue]`.
+pub(in crate::spaces) fn xyz_to_lch<T: Float + Send + Sync>(xyz: &Xyz<T>) -> Result<[T; 3]> {
+    let [lightness, u, v] = xyz_to_luv(xyz)?;
+    let hue = normalize_hue(v.atan2(u).to_degrees())?;
+    Ok([lightness, u.hypot(v), hue])
+}
+
+/// Convert cylindrical `LCh(uv)` components `[lightness, chroma, hue]` to an XYZ colour.
+/// Results that fall marginally outside the valid XYZ ranges through rounding are clamped.
+pub(in crate::spaces) fn lch_to_xyz<T: Float + Send + Sync>(lch: [T; 3]) -> Result<Xyz<T>> {
+    let [lightness, chroma, hue] = lch;
+    let (sin, cos) = hue.to_radians().sin_cos();
+    luv_to_xyz([lightness, chroma * cos, chroma * sin])
+}
+
+/// Chromaticity coordinates (u', v') of the D65 reference white.
+pub(in crate::spaces) fn white_chromaticity<T: Float + Send + Sync>() -> Result<(T, T)> {
+    let [x, y, z] = Xyz::d65_reference_white()?.components();
+    let denominator = x + safe_constant::<f64, T>(15.0)? * y + safe_constant::<f64, T>(3.0)? * z;
+    Ok((
+        safe_constant::<f64, T>(4.0)? * x / denominator,
+        safe_constant::<f64, T>(9.0)? * y / denominator,
+    ))
+}
+
+/// Convert relative luminance to CIE lightness.
+fn y_to_lightness<T: Float + Send + Sync>(y: T) -> Result<T> {
+    if y <= safe_constant(EPSILON)? {
+        return Ok(y * safe_constant(KAPPA)?);
+    }
+    Ok(safe_constant::<f64, T>(116.0)? * y.cbrt() - safe_constant(16.0)?)
+}
+
+/// Convert CIE lightness to relative luminance.
+pub(in crate::spaces) fn lightness_to_y<T: Float + Send + Sync>(lightness: T) -> Result<T> {
+    if lightness <= safe_constant(8.0)? {
+        return Ok(lightness / safe_constant(KAPPA)?);
+    }
+    Ok(((lightness + safe_constant(16.0)?) / safe_constant(116.0)?).powi(3))
+}
@@ -1,32 +1,52 @@
 //! This module provides implementations of various colour spaces.
 
+mod adobe_rgb;
+mod cmyk;
+mod display_p3;
 mod grey;
 mod grey_alpha;
 mod hsl;
 mod hsl_alpha;
+mod hsluv;
 mod hsv;
 mod hsv_alpha;
 mod lab;
 mod lab_alpha;
+mod linear_rgb;
+mod luv;
+mod oklab;
 mod rgb;
 mod rgb_alpha;
+#[cfg(feature = "spectral")]
+mod spectral;
 mod srgb;
 mod srgb_alpha;
 mod transparent;
+mod xyy;
 mod xyz;
 mod xyz_alpha;
 
+pub use adobe_rgb::AdobeRgb;
+pub use cmyk::Cmyk;
+pub use display_p3::DisplayP3;
 pub use grey::Grey;
 pub use grey_alpha::GreyAlpha;
 pub use hsl::Hsl;
 pub use hsl_alpha::HslAlpha;
+pub use hsluv::{Hpluv, Hsluv};
 pub use hsv::Hsv;
 pub use hsv_alpha::HsvAlpha;
 pub use lab::Lab;
 pub use lab_alpha::LabAlpha;
+pub use linear_rgb::LinearRgb;
+pub use luv::{Lchuv, Luv};
+pub use oklab::Oklab;
 pub use rgb::Rgb;
 pub use rgb_alpha::RgbAlpha;
-pub use srgb::Srgb;
+#[cfg(feature = "spectral")]
+pub use spectral::Spectral;
+pub use srgb::{GammaPrecision, Srgb};
 pub use srgb_alpha::SrgbAlpha;
+pub use xyy::XyY;
 pub use xyz::Xyz;
 pub use xyz_alpha::XyzAlpha;
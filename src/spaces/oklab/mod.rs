@@ -0,0 +1,385 @@
+//! Oklab colour representation.
+//!
+//! Oklab is a perceptual colour space designed by Björn Ottosson in 2020. It expresses colour as three values:
+//! - L for perceptual lightness (0 to 1)
+//! - a from green (-) to red (+)
+//! - b from blue (-) to yellow (+)
+//!
+//! Compared with CIELAB, Oklab keeps hue far more constant when lightness or chroma change, which makes
+//! it well suited to interpolating gradients, particularly through blues.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{
+        Result, clamp_component_range, clamp_unit_component, format_terminal_color, safe_constant, validate_component_range,
+        validate_interpolation_factor, validate_unit_component,
+    },
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// Largest magnitude accepted for the a and b components.
+const MAX_OPPONENT: f64 = 0.5;
+
+/// Linear sRGB to LMS cone response matrix (M1).
+const LINEAR_RGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+    [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+    [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+
+/// Non-linear LMS to Oklab matrix (M2).
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.210_454_255_3, 0.793_617_785_0, -0.004_072_046_8],
+    [1.977_998_495_1, -2.428_592_205_0, 0.450_593_709_9],
+    [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766_0],
+];
+
+/// Oklab to non-linear LMS matrix (inverse of M2).
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.396_337_777_4, 0.215_803_757_3],
+    [1.0, -0.105_561_345_8, -0.063_854_172_8],
+    [1.0, -0.089_484_177_5, -1.291_485_548_0],
+];
+
+/// LMS cone response to linear sRGB matrix (inverse of M1).
+const LMS_TO_LINEAR_RGB: [[f64; 3]; 3] = [
+    [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+    [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+    [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701_0],
+];
+
+/// Oklab colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct Oklab<T: Float + Send + Sync> {
+    /// Lightness component in range [0, 1].
+    lightness: T,
+    /// Green-red component in range [-0.5, 0.5].
+    a: T,
+    /// Blue-yellow component in range [-0.5, 0.5].
+    b: T,
+}
+
+impl<T: Float + Send + Sync> Oklab<T> {
+    /// Create a new `Oklab` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The L component, must be in range [0, 1]
+    /// * `a` - The a component, must be in range [-0.5, 0.5]
+    /// * `b` - The b component, must be in range [-0.5, 0.5]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if lightness is outside [0, 1] or if a/b are outside [-0.5, 0.5].
+    pub fn new(lightness: T, a: T, b: T) -> Result<Self> {
+        let max_opponent = safe_constant::<f64, T>(MAX_OPPONENT)?;
+
+        validate_unit_component(lightness, "lightness")?;
+        validate_component_range(a, "a", -max_opponent, max_opponent)?;
+        validate_component_range(b, "b", -max_opponent, max_opponent)?;
+
+        Ok(Self { lightness, a, b })
+    }
+
+    /// Get the `lightness` component (L).
+    pub const fn lightness(&self) -> T {
+        self.lightness
+    }
+
+    /// Get the `a` component.
+    pub const fn a(&self) -> T {
+        self.a
+    }
+
+    /// Get the `b` component.
+    pub const fn b(&self) -> T {
+        self.b
+    }
+
+    /// Set the `lightness` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - The new L value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_lightness(&mut self, lightness: T) -> Result<()> {
+        validate_unit_component(lightness, "lightness")?;
+        self.lightness = lightness;
+        Ok(())
+    }
+
+    /// Set the `a` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The new a value, must be in range [-0.5, 0.5]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [-0.5, 0.5].
+    pub fn set_a(&mut self, a: T) -> Result<()> {
+        let max_opponent = safe_constant::<f64, T>(MAX_OPPONENT)?;
+        validate_component_range(a, "a", -max_opponent, max_opponent)?;
+        self.a = a;
+        Ok(())
+    }
+
+    /// Set the `b` component with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The new b value, must be in range [-0.5, 0.5]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [-0.5, 0.5].
+    pub fn set_b(&mut self, b: T) -> Result<()> {
+        let max_opponent = safe_constant::<f64, T>(MAX_OPPONENT)?;
+        validate_component_range(b, "b", -max_opponent, max_opponent)?;
+        self.b = b;
+        Ok(())
+    }
+
+    /// Create an `Oklab` colour from a linear RGB colour.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - The linear RGB colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn from_rgb(rgb: &Rgb<T>) -> Result<Self> {
+        let lms = multiply(&LINEAR_RGB_TO_LMS, rgb.components())?;
+        let [lightness, a, b] = multiply(&LMS_TO_OKLAB, lms.map(T::cbrt))?;
+
+        // Rounding in the matrices leaves white and black marginally outside the valid ranges
+        Self::from_components_clamped([lightness, a, b])
+    }
+
+    /// Calculate the perceptual colour difference as the Euclidean distance in Oklab space.
+    pub fn distance(&self, other: &Self) -> T {
+        let dl = self.lightness - other.lightness;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for Oklab<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_oklab()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        Srgb::from_bytes(bytes)?.to_oklab()
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_oklab()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.lightness, self.a, self.b]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [lightness, a, b] = components;
+        Self::new(lightness, a, b)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [lightness, a, b] = components;
+        let max_opponent = safe_constant::<f64, T>(MAX_OPPONENT)?;
+
+        Self::new(
+            clamp_unit_component(lightness),
+            clamp_component_range(a, -max_opponent, max_opponent),
+            clamp_component_range(b, -max_opponent, max_opponent),
+        )
+    }
+
+    /// Linear interpolate between two Oklab colours.
+    ///
+    /// Oklab is close to perceptually uniform with stable hues, so linear interpolation
+    /// in this space produces smooth gradients without hue shifts.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.lightness * (T::one() - t) + rhs.lightness * t,
+            lhs.a * (T::one() - t) + rhs.a * t,
+            lhs.b * (T::one() - t) + rhs.b * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Oklab<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_oklab()
+    }
+
+    fn to_oklab(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_rgb()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_rgb()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    /// Convert to linear RGB, clamping colours outside the sRGB gamut.
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        let lms = multiply(&OKLAB_TO_LMS, self.components())?;
+        let [red, green, blue] = multiply(&LMS_TO_LINEAR_RGB, lms.map(|x| x * x * x))?;
+        Rgb::new(
+            clamp_unit_component(red),
+            clamp_unit_component(green),
+            clamp_unit_component(blue),
+        )
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_rgb()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        self.to_rgb()?.to_xyz()
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Oklab<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let rgb = self.to_rgb()?;
+        let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+/// Multiply a 3-vector by a 3x3 matrix of constants.
+fn multiply<T: Float + Send + Sync>(matrix: &[[f64; 3]; 3], vector: [T; 3]) -> Result<[T; 3]> {
+    let mut result = [T::zero(); 3];
+    for (output, row) in result.iter_mut().zip(matrix) {
+        for (&coefficient, &value) in row.iter().zip(&vector) {
+            *output = *output + safe_constant::<f64, T>(coefficient)? * value;
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn white_and_black_round_trip() -> Result<()> {
+        let white = Oklab::from_rgb(&Rgb::<f64>::new(1.0, 1.0, 1.0)?)?;
+        let black = Oklab::from_rgb(&Rgb::<f64>::new(0.0, 0.0, 0.0)?)?;
+
+        for (lhs, rhs) in white.components().iter().zip([1.0, 0.0, 0.0]) {
+            assert!((lhs - rhs).abs() < 1e-4);
+        }
+        assert!(black.components().iter().all(|component| component.abs() < 1e-12));
+
+        for component in white.to_rgb()?.components() {
+            assert!((component - 1.0).abs() < 1e-4);
+        }
+        assert!(black.to_rgb()?.components().iter().all(|component| component.abs() < 1e-12));
+        Ok(())
+    }
+
+    #[test]
+    fn red_matches_reference_values() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?.to_oklab()?;
+
+        for (lhs, rhs) in red.components().iter().zip([0.627_955, 0.224_863, 0.125_846]) {
+            assert!((lhs - rhs).abs() < 1e-4);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lerp_interpolates_components_linearly() -> Result<()> {
+        let lhs = Oklab::<f64>::new(0.2, -0.1, 0.05)?;
+        let rhs = Oklab::<f64>::new(0.8, 0.1, -0.15)?;
+
+        let mid = Oklab::lerp(&lhs, &rhs, 0.5)?;
+        for (value, expected) in mid.components().iter().zip([0.5, 0.0, -0.05]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        Ok(())
+    }
+}
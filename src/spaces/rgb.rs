@@ -1,29 +1,55 @@
-//! RGB colour representation.
-
+//! Linear RGB colour representation.
+//!
+//! `Rgb` stores linear-light components using the sRGB primaries and D65 white point, and is the hub that
+//! all other colour spaces convert through.
+//!
+//! Hex strings and byte arrays are sRGB-encoded, as they are everywhere else, so `Rgb::from_hex`
+//! decodes them to linear light and `Rgb::to_hex` re-encodes them. Use `Srgb` to work with the
+//! gamma-encoded components directly, or `LinearRgb` to write the linear components out as integers unencoded.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
     error::{
-        ColourParsingError, Result, component_to_u8, format_terminal_color, parse_hex_component, safe_constant,
-        u8_to_component, validate_interpolation_factor, validate_unit_component,
+        ChromaticError, Result, clamp_unit_component, format_terminal_color, safe_constant, validate_interpolation_factor,
+        validate_unit_component,
     },
-    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    scheme::{MAX_SCHEME_LIGHTNESS, MIN_SCHEME_LIGHTNESS, SchemeKind},
+    spaces::{
+        Grey, GreyAlpha, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, LinearRgb, RgbAlpha, Srgb, SrgbAlpha, Xyz,
+        XyzAlpha,
+    },
+    traits::{Colour, Convert, Gamut},
+    transfer::TransferFunction,
 };
 
-/// RGB colour representation.
+/// Linear RGB colour representation.
 #[derive(Debug, Clone, Copy)]
 pub struct Rgb<T: Float + Send + Sync> {
-    /// Red component in range [0, 1].
+    /// Linear red component in range [0, 1].
     red: T,
-    /// Green component in range [0, 1].
+    /// Linear green component in range [0, 1].
     green: T,
-    /// Blue component in range [0, 1].
+    /// Linear blue component in range [0, 1].
     blue: T,
 }
 
+impl<T: Float + Send + Sync> From<LinearRgb<T>> for Rgb<T> {
+    fn from(colour: LinearRgb<T>) -> Self {
+        Self {
+            red: colour.red(),
+            green: colour.green(),
+            blue: colour.blue(),
+        }
+    }
+}
+
 impl<T: Float + Send + Sync> Rgb<T> {
     /// Create a new `Rgb` instance with validation.
     ///
@@ -125,87 +151,208 @@ impl<T: Float + Send + Sync> Rgb<T> {
         self.blue = blue;
         Ok(())
     }
+
+    /// Create a linear `Rgb` colour from signal values encoded with the given transfer function.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The encoded red component, must be in range [0, 1]
+    /// * `green` - The encoded green component, must be in range [0, 1]
+    /// * `blue` - The encoded blue component, must be in range [0, 1]
+    /// * `transfer` - The transfer function the components are encoded with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1] or decoding fails.
+    pub fn from_encoded(red: T, green: T, blue: T, transfer: TransferFunction<T>) -> Result<Self> {
+        Self::new(
+            transfer.decode(red)?.min(T::one()),
+            transfer.decode(green)?.min(T::one()),
+            transfer.decode(blue)?.min(T::one()),
+        )
+    }
+
+    /// Encode the colour's linear components with the given transfer function.
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer` - The transfer function to encode with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails.
+    pub fn to_encoded(&self, transfer: TransferFunction<T>) -> Result<[T; 3]> {
+        Ok([
+            transfer.encode(self.red)?,
+            transfer.encode(self.green)?,
+            transfer.encode(self.blue)?,
+        ])
+    }
+
+    /// Compute the photographic negative by inverting each channel (`1 - component`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn negate(&self) -> Result<Self> {
+        Self::new(T::one() - self.red, T::one() - self.green, T::one() - self.blue)
+    }
+
+    /// Create a tint by mixing toward white in linear light.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The proportion of white, must be in range [0, 1]; 1 gives pure white
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is outside [0, 1].
+    pub fn tint(&self, amount: T) -> Result<Self> {
+        Self::lerp(self, &Self::new(T::one(), T::one(), T::one())?, amount)
+    }
+
+    /// Create a shade by mixing toward black in linear light.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The proportion of black, must be in range [0, 1]; 1 gives pure black
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is outside [0, 1].
+    pub fn shade(&self, amount: T) -> Result<Self> {
+        Self::lerp(self, &Self::new(T::zero(), T::zero(), T::zero())?, amount)
+    }
+
+    /// Increase the perceived lightness by adjusting L* in CIE L*a*b* (see [`Lab::lighten`]).
+    ///
+    /// Colours pushed outside the sRGB gamut have their chroma reduced, preserving the new lightness and hue
+    /// (see [`Lab::map_to_srgb_gamut`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The amount to add to L*, on its [0, 100] scale
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from Lab fails.
+    pub fn lighten(&self, delta: T) -> Result<Self> {
+        self.to_lab()?.lighten(delta)?.map_to_srgb_gamut()?.to_rgb()
+    }
+
+    /// Decrease the perceived lightness by adjusting L* in CIE L*a*b* (see [`Lab::darken`]).
+    ///
+    /// Out-of-gamut results are handled as in [`Rgb::lighten`].
+    ///
+    /// # Arguments
+    ///
+    /// * `delta` - The amount to subtract from L*, on its [0, 100] scale
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from Lab fails.
+    pub fn darken(&self, delta: T) -> Result<Self> {
+        self.to_lab()?.darken(delta)?.map_to_srgb_gamut()?.to_rgb()
+    }
+
+    /// Convert to greyscale by relative luminance, weighting the linear channels by 0.2126, 0.7152 and 0.0722.
+    ///
+    /// Unlike [`Convert::to_grey`], which averages the channels, this matches the perceived brightness
+    /// of saturated colours, so pure green is much lighter than pure blue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails or the resulting grey is invalid.
+    pub fn to_grey_luminance(&self) -> Result<Grey<T>> {
+        Grey::new(clamp_unit_component(
+            self.red * safe_constant(0.2126)? + self.green * safe_constant(0.7152)? + self.blue * safe_constant(0.0722)?,
+        ))
+    }
+
+    /// Generate a colour scheme around this colour, starting with the colour itself.
+    ///
+    /// Hues are rotated in `Hsluv`, which keeps the perceived lightness of each hue equal to the seed.
+    /// Lightness variations are clamped to a usable range, so seeds close to black or white may
+    /// produce repeated colours.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of scheme to generate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if colour space conversion fails.
+    pub fn scheme(&self, kind: SchemeKind) -> Result<Vec<Self>> {
+        let seed = self.to_hsluv()?;
+        let min_lightness = safe_constant(MIN_SCHEME_LIGHTNESS)?;
+        let max_lightness = safe_constant(MAX_SCHEME_LIGHTNESS)?;
+
+        kind.offsets()
+            .iter()
+            .map(|&(hue_offset, lightness_offset)| {
+                if hue_offset == 0.0 && lightness_offset == 0.0 {
+                    return Ok(*self);
+                }
+
+                let lightness = if lightness_offset == 0.0 {
+                    seed.lightness()
+                } else {
+                    (seed.lightness() + safe_constant(lightness_offset)?)
+                        .max(min_lightness)
+                        .min(max_lightness)
+                };
+                Hsluv::new(seed.hue() + safe_constant(hue_offset)?, seed.saturation(), lightness)?.to_rgb()
+            })
+            .collect()
+    }
 }
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Rgb<T> {
-    fn from_hex(hex: &str) -> Result<Self> {
-        let hex = hex.trim();
-
-        // Check for # prefix
-        let components = hex
-            .strip_prefix('#')
-            .ok_or_else(|| ColourParsingError::MissingHexPrefix(hex.to_string()))?;
-
-        let (red, green, blue) = match components.len() {
-            // Short form: #RGB
-            3 => {
-                let red = parse_hex_component(&components[0..1], "red")?;
-                let green = parse_hex_component(&components[1..2], "green")?;
-                let blue = parse_hex_component(&components[2..3], "blue")?;
-
-                // Expand short form (e.g., #F00 becomes #FF0000)
-                let scale = safe_constant(255.0)?;
-                let expanded_red = red * 17;
-                let expanded_green = green * 17;
-                let expanded_blue = blue * 17;
-
-                (
-                    u8_to_component(expanded_red, scale)?,
-                    u8_to_component(expanded_green, scale)?,
-                    u8_to_component(expanded_blue, scale)?,
-                )
-            }
-            // Long form: #RRGGBB
-            6 => {
-                let red = parse_hex_component(&components[0..2], "red")?;
-                let green = parse_hex_component(&components[2..4], "green")?;
-                let blue = parse_hex_component(&components[4..6], "blue")?;
-
-                let scale = safe_constant(255.0)?;
-                (
-                    u8_to_component(red, scale)?,
-                    u8_to_component(green, scale)?,
-                    u8_to_component(blue, scale)?,
-                )
-            }
-            _ => {
-                return Err(ColourParsingError::InvalidHexLength {
-                    actual: components.len(),
-                }
-                .into());
-            }
-        };
+    // Hex strings and bytes are always sRGB-encoded, so they are decoded to linear light on the way in
+    // and encoded on the way out.
 
-        Self::new(red, green, blue)
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_rgb()
     }
 
     fn to_hex(&self) -> Result<String> {
-        let scale = safe_constant(255.0)?;
-        let red = component_to_u8(self.red, "red", scale)?;
-        let green = component_to_u8(self.green, "green", scale)?;
-        let blue = component_to_u8(self.blue, "blue", scale)?;
-
-        Ok(format!("#{red:02X}{green:02X}{blue:02X}"))
+        self.to_srgb()?.to_hex()
     }
 
     fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
-        let scale = safe_constant(255.0)?;
-        let red = u8_to_component(bytes[0], scale)?;
-        let green = u8_to_component(bytes[1], scale)?;
-        let blue = u8_to_component(bytes[2], scale)?;
-        Self::new(red, green, blue)
+        Srgb::from_bytes(bytes)?.to_rgb()
     }
 
     fn to_bytes(self) -> Result<[u8; 3]> {
-        let scale = safe_constant(255.0)?;
-        let red = component_to_u8(self.red, "red", scale)?;
-        let green = component_to_u8(self.green, "green", scale)?;
-        let blue = component_to_u8(self.blue, "blue", scale)?;
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_rgb()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.red, self.green, self.blue]
+    }
 
-        Ok([red, green, blue])
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components;
+        Self::new(red, green, blue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components.map(clamp_unit_component);
+        Self::new(red, green, blue)
     }
 
     /// Linear interpolate between two RGB colours.
+    ///
+    /// Components are interpolated directly in linear light, without passing through Lab,
+    /// which models the physical mixing of light. For perceptually even blends, interpolate `Lab` colours instead.
     fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
         validate_interpolation_factor(t)?;
 
@@ -218,6 +365,12 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Rgb<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Rgb<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_rgb()
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
         // Use simple average for RGB to greyscale conversion
         // For perceptually accurate conversion, use luminance weights via XYZ
@@ -228,65 +381,9 @@ impl<T: Float + Send + Sync> Convert<T> for Rgb<T> {
         GreyAlpha::new((self.red + self.green + self.blue) / safe_constant(3.0)?, T::one())
     }
 
-    #[expect(
-        clippy::similar_names,
-        reason = "f60 and f360 are used for hue calculations. Their names are similar only because they represent different numerical constants."
-    )]
     fn to_hsl(&self) -> Result<Hsl<T>> {
-        let r = self.red();
-        let g = self.green();
-        let b = self.blue();
-
-        let max = r.max(g.max(b));
-        let min = r.min(g.min(b));
-        let delta = max - min;
-
-        // Calculate lightness
-        let lightness = (max + min) / safe_constant(2.0)?;
-
-        // If max equals min, the color is a shade of gray (no hue or saturation)
-        if delta.abs() < T::epsilon() {
-            return Hsl::new(T::zero(), T::zero(), lightness);
-        }
-
-        // Calculate saturation
-        let saturation = if lightness <= safe_constant(0.5)? {
-            delta / (max + min)
-        } else {
-            delta / (safe_constant::<f64, T>(2.0)? - max - min)
-        };
-
-        // Constants
-        let f360 = safe_constant(360.0)?;
-        let f60 = safe_constant(60.0)?;
-
-        // Calculate hue
-        let hue = if (r - max).abs() < T::epsilon() {
-            // Red is max
-            let segment = (g - b) / delta;
-            let base_hue = segment * f60;
-            // If green is less than blue, add 360 degrees
-            if g < b { base_hue + f360 } else { base_hue }
-        } else if (g - max).abs() < T::epsilon() {
-            // Green is max
-            let segment = (b - r) / delta;
-            segment * f60 + safe_constant(120.0)?
-        } else {
-            // Blue is max
-            let segment = (r - g) / delta;
-            segment * f60 + safe_constant(240.0)?
-        };
-
-        // Normalize hue to [0, 360) range
-        let normalized_hue = if hue >= f360 {
-            hue - f360
-        } else if hue < T::zero() {
-            hue + f360
-        } else {
-            hue
-        };
-
-        Hsl::new(normalized_hue, saturation, lightness)
+        // HSL is defined over the gamma-encoded sRGB channels, as in CSS
+        self.to_srgb()?.to_hsl()
     }
 
     fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
@@ -306,7 +403,8 @@ impl<T: Float + Send + Sync> Convert<T> for Rgb<T> {
         let value = max;
 
         // Handle achromatic case
-        if delta.abs() < T::epsilon() {
+        // Compare exactly, so that dark near-greys keep their saturation and round-trip accurately
+        if delta == T::zero() {
             return Hsv::new(T::zero(), T::zero(), value);
         }
 
@@ -391,7 +489,8 @@ impl<T: Float + Send + Sync> Convert<T> for Rgb<T> {
             + self.green() * safe_constant(0.119_192_0)?
             + self.blue() * safe_constant(0.950_304_1)?;
 
-        Xyz::new(x, y, z)
+        // The luminance coefficients sum to slightly more than 1, so clamp to the valid range
+        Xyz::new(x, y.min(T::one()), z)
     }
 
     fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
@@ -402,7 +501,217 @@ impl<T: Float + Send + Sync> Convert<T> for Rgb<T> {
 
 impl<T: Float + Send + Sync> Display for Rgb<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let color_string = format_terminal_color(self.red, self.green, self.blue, PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+/// Decode sRGB-encoded bytes to linear light (see `Rgb::from_bytes`).
+impl<T: Float + Send + Sync> TryFrom<[u8; 3]> for Rgb<T> {
+    type Error = ChromaticError;
+
+    fn try_from(bytes: [u8; 3]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Encode as sRGB bytes (see `Rgb::to_bytes`).
+impl<T: Float + Send + Sync> TryFrom<Rgb<T>> for [u8; 3] {
+    type Error = ChromaticError;
+
+    fn try_from(colour: Rgb<T>) -> Result<Self> {
+        colour.to_bytes()
+    }
+}
+
+/// Compares colours at byte precision, so colours that encode to the same `to_bytes` value are equal.
+/// Use `ApproxEq` to compare the float components directly.
+impl<T: Float + Send + Sync> PartialEq for Rgb<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ok() == other.to_bytes().ok()
+    }
+}
+
+impl<T: Float + Send + Sync> Eq for Rgb<T> {}
+
+/// Hashes the `to_bytes` value, so colours differing by less than one byte step hash alike.
+/// This is byte precision rather than float exact, consistent with the `PartialEq` implementation.
+impl<T: Float + Send + Sync> Hash for Rgb<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().ok().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    use crate::spaces::GammaPrecision;
+
+    #[test]
+    fn lerp_averages_components_in_linear_light() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+        let green = Rgb::<f64>::new(0.0, 1.0, 0.0)?;
+
+        let mid = Rgb::lerp(&red, &green, 0.5)?;
+        assert!((mid.red() - 0.5).abs() < 1e-12);
+        assert!((mid.green() - 0.5).abs() < 1e-12);
+        assert!(mid.blue().abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn linear_rgb_to_srgb_applies_gamma_encoding() -> Result<()> {
+        let linear = Rgb::<f64>::new(0.5, 0.0, 1.0)?;
+        let encoded = linear.to_srgb()?;
+
+        assert!((encoded.red() - Srgb::gamma_encode_with(0.5, GammaPrecision::Exact)?).abs() < 1e-4);
+        assert!(encoded.red() > 0.73 && encoded.red() < 0.74);
+        assert!(encoded.green().abs() < 1e-12);
+        assert!((encoded.blue() - 1.0).abs() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn hex_round_trips_through_srgb() -> Result<()> {
+        let colour = Rgb::<f64>::from_hex("#808080")?;
+        let expected = Srgb::gamma_decode(128.0 / 255.0)?;
+
+        assert!((colour.red() - expected).abs() < 1e-12);
+        assert_eq!(colour.to_hex()?, "#808080");
+        assert_eq!(colour.to_srgb()?.to_hex()?, "#808080");
+        Ok(())
+    }
+
+    #[test]
+    fn display_emits_hex_and_alternate_emits_ansi() -> Result<()> {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0)?;
+
+        assert_eq!(format!("{red}"), "#FF0000");
+        assert_eq!(format!("{red:#}"), format!("\x1b[38;2;255;0;0m{PRINT_BLOCK}\x1b[0m"));
+        Ok(())
+    }
+
+    #[test]
+    fn scheme_has_expected_count_per_kind() -> Result<()> {
+        let seed = Rgb::<f64>::from_hex("#3a7bd5")?;
+
+        for (kind, count) in [
+            (SchemeKind::Monochromatic, 5),
+            (SchemeKind::Analogous, 5),
+            (SchemeKind::Complementary, 5),
+            (SchemeKind::Triadic, 3),
+            (SchemeKind::Tetradic, 4),
+        ] {
+            let scheme = seed.scheme(kind)?;
+            assert_eq!(scheme.len(), count);
+            assert_eq!(scheme[0], seed);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn complementary_scheme_contains_opposite_hue() -> Result<()> {
+        let seed = Rgb::<f64>::from_hex("#3a7bd5")?;
+        let seed_hue = seed.to_hsluv()?.hue();
+
+        let found = seed.scheme(SchemeKind::Complementary)?.iter().any(|colour| {
+            colour.to_hsluv().is_ok_and(|hsluv| {
+                let difference = (hsluv.hue() - seed_hue).rem_euclid(360.0);
+                (difference - 180.0).abs() < 1.0
+            })
+        });
+        assert!(found);
+        Ok(())
+    }
+
+    #[test]
+    fn luminance_grey_weights_green_above_blue() -> Result<()> {
+        let green = Rgb::<f64>::new(0.0, 1.0, 0.0)?.to_grey_luminance()?;
+        let blue = Rgb::<f64>::new(0.0, 0.0, 1.0)?.to_grey_luminance()?;
+
+        assert!((green.grey() - 0.7152).abs() < 1e-12);
+        assert!((blue.grey() - 0.0722).abs() < 1e-12);
+        assert!(green.grey() > 5.0 * blue.grey());
+
+        // The averaging conversion is unchanged and treats both primaries alike
+        let averaged = Rgb::<f64>::new(0.0, 1.0, 0.0)?.to_grey()?;
+        assert!((averaged.grey() - 1.0 / 3.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn byte_arrays_convert_to_and_from_rgb() -> Result<()> {
+        let red: Rgb<f32> = [255, 0, 0].try_into()?;
+
+        for (value, expected) in red.components().iter().zip([1.0, 0.0, 0.0]) {
+            assert!((value - expected).abs() < 1e-4);
+        }
+        let bytes: [u8; 3] = Rgb::<f64>::try_from([12, 34, 56])?.try_into()?;
+        assert_eq!(bytes, [12, 34, 56]);
+        Ok(())
+    }
+
+    /// Hasher that records every byte written, so hashes can be compared without `std`.
+    #[derive(Debug, Default)]
+    struct RecordingHasher(Vec<u8>);
+
+    impl Hasher for RecordingHasher {
+        fn finish(&self) -> u64 {
+            self.0.iter().fold(0, |hash, &byte| hash.rotate_left(8) ^ u64::from(byte))
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    /// Collect the bytes a value feeds to its hasher.
+    fn hashed<H: Hash>(value: &H) -> Vec<u8> {
+        let mut hasher = RecordingHasher::default();
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    #[test]
+    fn colours_within_a_byte_step_hash_alike() -> Result<()> {
+        let lhs = Rgb::<f64>::new(0.2, 0.4, 0.6)?;
+        let rhs = Rgb::<f64>::new(0.200_01, 0.4, 0.6)?;
+        assert_eq!(lhs.to_bytes()?, rhs.to_bytes()?);
+        assert_eq!(lhs, rhs);
+        assert_eq!(hashed(&lhs), hashed(&rhs));
+        assert_ne!(hashed(&lhs), hashed(&Rgb::<f64>::new(0.3, 0.4, 0.6)?));
+
+        let (lhs, rhs) = (Grey::<f64>::new(0.2)?, Grey::<f64>::new(0.200_01)?);
+        assert_eq!(hashed(&lhs), hashed(&rhs));
+
+        let lhs = RgbAlpha::<f64>::new(0.2, 0.4, 0.6, 0.5)?;
+        let rhs = RgbAlpha::<f64>::new(0.2, 0.4, 0.6, 0.500_01)?;
+        assert_eq!(hashed(&lhs), hashed(&rhs));
+        Ok(())
+    }
+
+    #[test]
+    fn full_tint_and_shade_reach_white_and_black() -> Result<()> {
+        let colour = Rgb::<f64>::new(0.2, 0.5, 0.7)?;
+
+        assert!(
+            colour
+                .tint(1.0)?
+                .components()
+                .iter()
+                .all(|component| (component - 1.0).abs() < 1e-6)
+        );
+        assert!(colour.shade(1.0)?.components().iter().all(|component| component.abs() < 1e-6));
+        for (value, expected) in colour.tint(0.0)?.components().iter().zip(colour.components()) {
+            assert!((value - expected).abs() < 1e-6);
+        }
+        assert!(colour.tint(1.5).is_err() && colour.shade(-0.5).is_err());
+        Ok(())
+    }
+}
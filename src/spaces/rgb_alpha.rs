@@ -1,13 +1,21 @@
 //! RGB colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
-    error::{Result, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    error::{ChromaticError, Result, clamp_unit_component, validate_unit_component},
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, Srgb, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// RGB with alpha channel.
@@ -159,8 +167,182 @@ impl<T: Float + Send + Sync> RgbAlpha<T> {
         self.alpha = alpha;
         Ok(())
     }
+
+    /// Compute the photographic negative by inverting each colour channel, preserving alpha.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn negate(&self) -> Result<Self> {
+        Self::new_colour_with_alpha(self.colour.negate()?, self.alpha)
+    }
+
+    /// Get the components premultiplied by alpha, as `[red * alpha, green * alpha, blue * alpha, alpha]`.
+    #[must_use]
+    pub fn to_premultiplied(&self) -> [T; 4] {
+        [
+            self.red() * self.alpha,
+            self.green() * self.alpha,
+            self.blue() * self.alpha,
+            self.alpha,
+        ]
+    }
+
+    /// Create an `RgbAlpha` colour from components premultiplied by alpha.
+    ///
+    /// The colour channels are divided by alpha, and a zero alpha gives fully transparent black.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - The premultiplied `[red, green, blue, alpha]` components
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if alpha is outside the range [0, 1], or if any colour channel exceeds alpha.
+    pub fn from_premultiplied(parts: [T; 4]) -> Result<Self> {
+        let [red, green, blue, alpha] = parts;
+        validate_unit_component(alpha, "alpha")?;
+        if alpha <= T::zero() {
+            return Self::new(T::zero(), T::zero(), T::zero(), T::zero());
+        }
+
+        Self::new(red / alpha, green / alpha, blue / alpha, alpha)
+    }
+
+    /// Composite this colour over a background using the Porter-Duff source-over operator.
+    ///
+    /// The output alpha is `src_a + dst_a * (1 - src_a)`, and the channels are blended in linear light
+    /// as premultiplied colours before being divided by the output alpha.
+    /// If both colours are fully transparent the result is fully transparent black.
+    ///
+    /// # Arguments
+    ///
+    /// * `background` - The colour underneath this one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting components are invalid.
+    pub fn over(&self, background: &Self) -> Result<Self> {
+        let background_weight = background.alpha * (T::one() - self.alpha);
+        let alpha = self.alpha + background_weight;
+        if alpha <= T::zero() {
+            return Self::new(T::zero(), T::zero(), T::zero(), T::zero());
+        }
+
+        let blend = |src: T, dst: T| clamp_unit_component((src * self.alpha + dst * background_weight) / alpha);
+        Self::new(
+            blend(self.red(), background.red()),
+            blend(self.green(), background.green()),
+            blend(self.blue(), background.blue()),
+            clamp_unit_component(alpha),
+        )
+    }
 }
 
 impl_transparent_colour!(RgbAlpha<T>, Rgb<T>, 3);
-impl_transparent_convert!(RgbAlpha<T>, Rgb<T>);
+impl_transparent_convert!(RgbAlpha<T>, Rgb<T>, {
+    /// Format as CSS `rgba()` functional notation of the gamma-encoded colour (see `SrgbAlpha::to_css_string`).
+    fn to_css_string(&self) -> Result<String> {
+        self.to_srgb_alpha()?.to_css_string()
+    }
+});
 impl_transparent_display!(RgbAlpha<T>);
+impl_transparent_alpha!(RgbAlpha<T>);
+
+/// Decode sRGB-encoded bytes to linear light (see `RgbAlpha::from_bytes`).
+impl<T: Float + Send + Sync> TryFrom<[u8; 4]> for RgbAlpha<T> {
+    type Error = ChromaticError;
+
+    fn try_from(bytes: [u8; 4]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Encode as sRGB bytes (see `RgbAlpha::to_bytes`).
+impl<T: Float + Send + Sync> TryFrom<RgbAlpha<T>> for [u8; 4] {
+    type Error = ChromaticError;
+
+    fn try_from(colour: RgbAlpha<T>) -> Result<Self> {
+        colour.to_bytes()
+    }
+}
+
+/// Compares colours at byte precision, so colours that encode to the same `to_bytes` value are equal.
+/// Use `ApproxEq` to compare the float components directly.
+impl<T: Float + Send + Sync> PartialEq for RgbAlpha<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ok() == other.to_bytes().ok()
+    }
+}
+
+impl<T: Float + Send + Sync> Eq for RgbAlpha<T> {}
+
+/// Hashes the `to_bytes` value, so colours differing by less than one byte step hash alike.
+/// This is byte precision rather than float exact, consistent with the `PartialEq` implementation.
+impl<T: Float + Send + Sync> Hash for RgbAlpha<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().ok().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_red_over_blue_is_opaque_purple() -> Result<()> {
+        let red = RgbAlpha::<f64>::new(1.0, 0.0, 0.0, 0.5)?;
+        let blue = RgbAlpha::<f64>::new(0.0, 0.0, 1.0, 1.0)?;
+
+        let blended = red.over(&blue)?;
+        for (value, expected) in blended.components().iter().zip([0.5, 0.0, 0.5, 1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn transparent_over_transparent_is_transparent_black() -> Result<()> {
+        let clear = RgbAlpha::<f64>::new(0.3, 0.6, 0.9, 0.0)?;
+
+        let blended = clear.over(&clear)?;
+        assert_eq!(blended.components().map(f64::to_bits), [0.0; 4].map(f64::to_bits));
+        Ok(())
+    }
+
+    #[test]
+    fn premultiplied_round_trip_restores_colour() -> Result<()> {
+        for alpha in [0.25, 0.5, 0.75, 1.0] {
+            let colour = RgbAlpha::<f64>::new(0.2, 0.6, 0.9, alpha)?;
+
+            let premultiplied = colour.to_premultiplied();
+            assert!((premultiplied[1] - 0.6 * alpha).abs() < 1e-12);
+            let restored = RgbAlpha::from_premultiplied(premultiplied)?;
+            for (lhs, rhs) in restored.components().iter().zip(colour.components()) {
+                assert!((lhs - rhs).abs() < 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn zero_alpha_premultiplied_is_transparent_black() -> Result<()> {
+        let colour = RgbAlpha::<f64>::from_premultiplied([0.0, 0.0, 0.0, 0.0])?;
+
+        assert_eq!(colour.components().map(f64::to_bits), [0.0; 4].map(f64::to_bits));
+        assert!(RgbAlpha::<f64>::from_premultiplied([0.8, 0.0, 0.0, 0.5]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn byte_arrays_convert_to_and_from_rgb_alpha() -> Result<()> {
+        let red: RgbAlpha<f32> = [255, 0, 0, 255].try_into()?;
+
+        for (value, expected) in red.components().iter().zip([1.0, 0.0, 0.0, 1.0]) {
+            assert!((value - expected).abs() < 1e-4);
+        }
+        let bytes: [u8; 4] = RgbAlpha::<f64>::try_from([12, 34, 56, 78])?.try_into()?;
+        assert_eq!(bytes, [12, 34, 56, 78]);
+        Ok(())
+    }
+}
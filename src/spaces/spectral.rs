@@ -0,0 +1,351 @@
+//! Spectral reflectance colour representation.
+//!
+//! A `Spectral` colour stores the reflectance of a surface at a small number of wavelengths across the
+//! visible range, allowing physically plausible subtractive mixing by multiplying reflectances.
+//! Conversion from RGB uses the basis reflectance curves of Smits (1999), and conversion to XYZ
+//! integrates the analytic CIE 1931 colour-matching function fit of Wyman, Sloan & Shirley (2013).
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{Result, format_terminal_color, safe_constant, validate_unit_component},
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// Number of wavelength samples in a spectrum.
+const NUM_SAMPLES: usize = 10;
+
+/// Shortest sampled wavelength in nanometres.
+const MIN_WAVELENGTH: f64 = 380.0;
+
+/// Longest sampled wavelength in nanometres.
+const MAX_WAVELENGTH: f64 = 720.0;
+
+/// Basis reflectance curves for converting RGB to a spectrum (Smits 1999).
+/// Ordered as white, cyan, magenta, yellow, red, green, blue.
+const BASIS: [[f64; NUM_SAMPLES]; 7] = [
+    [1.0000, 1.0000, 0.9999, 0.9993, 0.9992, 0.9998, 1.0000, 1.0000, 1.0000, 1.0000],
+    [0.9710, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0000, 0.0000, 0.0000],
+    [1.0000, 1.0000, 0.9685, 0.2229, 0.0000, 0.0458, 0.8369, 1.0000, 1.0000, 0.9959],
+    [0.0001, 0.0000, 0.1088, 0.6651, 1.0000, 1.0000, 0.9996, 0.9586, 0.9685, 0.9840],
+    [0.1012, 0.0515, 0.0000, 0.0000, 0.0000, 0.0000, 0.8325, 1.0149, 1.0149, 1.0149],
+    [0.0000, 0.0000, 0.0273, 0.7937, 1.0000, 0.9418, 0.1719, 0.0000, 0.0000, 0.0025],
+    [1.0000, 1.0000, 0.8916, 0.3323, 0.0000, 0.0000, 0.0003, 0.0369, 0.0483, 0.0496],
+];
+
+/// Index of each curve in `BASIS`.
+const WHITE: usize = 0;
+/// Index of the cyan basis curve.
+const CYAN: usize = 1;
+/// Index of the magenta basis curve.
+const MAGENTA: usize = 2;
+/// Index of the yellow basis curve.
+const YELLOW: usize = 3;
+/// Index of the red basis curve.
+const RED: usize = 4;
+/// Index of the green basis curve.
+const GREEN: usize = 5;
+/// Index of the blue basis curve.
+const BLUE: usize = 6;
+
+/// Spectral reflectance colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct Spectral<T: Float + Send + Sync> {
+    /// Reflectance at each sampled wavelength, each in range [0, 1].
+    reflectance: [T; NUM_SAMPLES],
+}
+
+impl<T: Float + Send + Sync> Spectral<T> {
+    /// Number of wavelength samples in a spectrum.
+    pub const NUM_SAMPLES: usize = NUM_SAMPLES;
+
+    /// Create a new `Spectral` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `reflectance` - The reflectance at each sampled wavelength, each must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any reflectance is outside the range [0, 1].
+    pub fn new(reflectance: [T; NUM_SAMPLES]) -> Result<Self> {
+        for &value in &reflectance {
+            validate_unit_component(value, "reflectance")?;
+        }
+
+        Ok(Self { reflectance })
+    }
+
+    /// Create a spectrum with the same reflectance at every wavelength.
+    ///
+    /// # Arguments
+    ///
+    /// * `reflectance` - The reflectance, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reflectance is outside the range [0, 1].
+    pub fn flat(reflectance: T) -> Result<Self> {
+        Self::new([reflectance; NUM_SAMPLES])
+    }
+
+    /// Get the reflectance at each sampled wavelength.
+    pub const fn reflectance(&self) -> [T; NUM_SAMPLES] {
+        self.reflectance
+    }
+
+    /// Get the sampled wavelengths in nanometres, evenly spaced from 380 to 720.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn wavelengths() -> Result<[T; NUM_SAMPLES]> {
+        let mut wavelengths = [T::zero(); NUM_SAMPLES];
+        for (i, wavelength) in wavelengths.iter_mut().enumerate() {
+            *wavelength = safe_constant(sample_wavelength(i))?;
+        }
+        Ok(wavelengths)
+    }
+
+    /// Create a spectrum from a linear RGB colour using the Smits basis reflectance curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - The colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn from_rgb(rgb: &Rgb<T>) -> Result<Self> {
+        let (r, g, b) = (rgb.red(), rgb.green(), rgb.blue());
+
+        // Start from the white curve scaled by the smallest component,
+        // then add the secondary and primary curves for the remaining differences
+        let terms = if r <= g && r <= b {
+            if g <= b {
+                [(WHITE, r), (CYAN, g - r), (BLUE, b - g)]
+            } else {
+                [(WHITE, r), (CYAN, b - r), (GREEN, g - b)]
+            }
+        } else if g <= r && g <= b {
+            if r <= b {
+                [(WHITE, g), (MAGENTA, r - g), (BLUE, b - r)]
+            } else {
+                [(WHITE, g), (MAGENTA, b - g), (RED, r - b)]
+            }
+        } else if r <= g {
+            [(WHITE, b), (YELLOW, r - b), (GREEN, g - r)]
+        } else {
+            [(WHITE, b), (YELLOW, g - b), (RED, r - g)]
+        };
+
+        let mut reflectance = [T::zero(); NUM_SAMPLES];
+        for (curve, weight) in terms {
+            for (value, &basis) in reflectance.iter_mut().zip(&BASIS[curve]) {
+                *value = *value + weight * safe_constant(basis)?;
+            }
+        }
+
+        // The basis curves slightly exceed 1 in places, so clamp to the valid range
+        Self::new(reflectance.map(|value| value.max(T::zero()).min(T::one())))
+    }
+
+    /// Mix two colours subtractively, as for pigments, by multiplying their reflectances.
+    /// With only ten samples the result is an approximation, and saturated mixtures tend to be darker than real paint.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The colour to mix with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting reflectances are invalid.
+    pub fn mix(&self, other: &Self) -> Result<Self> {
+        let mut reflectance = self.reflectance;
+        for (value, &other_value) in reflectance.iter_mut().zip(&other.reflectance) {
+            *value = *value * other_value;
+        }
+
+        Self::new(reflectance)
+    }
+}
+
+/// Centre wavelength of a sample in nanometres.
+#[expect(clippy::cast_precision_loss, reason = "Sample indices are tiny and exactly representable.")]
+fn sample_wavelength(index: usize) -> f64 {
+    MIN_WAVELENGTH + (MAX_WAVELENGTH - MIN_WAVELENGTH) * index as f64 / (NUM_SAMPLES - 1) as f64
+}
+
+/// Piecewise Gaussian used by the analytic colour-matching function fit.
+fn piecewise_gaussian(wavelength: f64, mean: f64, sigma_low: f64, sigma_high: f64) -> f64 {
+    let sigma = if wavelength < mean { sigma_low } else { sigma_high };
+    let t = (wavelength - mean) / sigma;
+    (-0.5 * t * t).exp()
+}
+
+/// CIE 1931 2° colour-matching functions (Wyman, Sloan & Shirley 2013 multi-lobe fit).
+fn colour_matching(wavelength: f64) -> [f64; 3] {
+    let lobe = |mean, sigma_low, sigma_high| piecewise_gaussian(wavelength, mean, sigma_low, sigma_high);
+
+    let x = 1.056f64.mul_add(
+        lobe(599.8, 37.9, 31.0),
+        0.362f64.mul_add(lobe(442.0, 16.0, 26.7), -0.065 * lobe(501.1, 20.4, 26.2)),
+    );
+    let y = 0.821f64.mul_add(lobe(568.8, 46.9, 40.5), 0.286 * lobe(530.9, 16.3, 31.1));
+    let z = 1.217f64.mul_add(lobe(437.0, 11.8, 36.0), 0.681 * lobe(459.0, 26.0, 13.8));
+    [x, y, z]
+}
+
+/// Integrated X, Y and Z weight of each sample, integrating the colour-matching functions in 1 nm steps
+/// over the band of wavelengths closest to the sample.
+#[expect(clippy::cast_precision_loss, reason = "Step counts are tiny and exactly representable.")]
+fn sample_weights() -> [[f64; 3]; NUM_SAMPLES] {
+    const STEPS: usize = 38;
+    let half_width = (MAX_WAVELENGTH - MIN_WAVELENGTH) / (NUM_SAMPLES - 1) as f64 / 2.0;
+
+    let mut weights = [[0.0; 3]; NUM_SAMPLES];
+    for (i, weight) in weights.iter_mut().enumerate() {
+        let start = sample_wavelength(i) - half_width;
+        for step in 0..STEPS {
+            let wavelength = start + 2.0 * half_width * (step as f64 + 0.5) / STEPS as f64;
+            for (total, value) in weight.iter_mut().zip(colour_matching(wavelength)) {
+                *total += value;
+            }
+        }
+    }
+    weights
+}
+
+impl<T: Float + Send + Sync> Convert<T> for Spectral<T> {
+    const GAMUT: Gamut = Gamut::Spectral;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        Self::from_rgb(&colour.to_rgb()?)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        let weights = sample_weights();
+        let white = Xyz::<T>::d65_reference_white()?;
+
+        // Integrate under an equal-energy illuminant, scaling each channel so that a perfect reflector
+        // maps to the D65 reference white
+        let mut components = [T::zero(); 3];
+        for (channel, (component, reference)) in components.iter_mut().zip([white.x(), white.y(), white.z()]).enumerate() {
+            let total: f64 = weights.iter().map(|weight| weight[channel]).sum();
+            for (&reflectance, weight) in self.reflectance.iter().zip(&weights) {
+                *component = *component + reflectance * safe_constant::<f64, T>(weight[channel] / total)?;
+            }
+            *component = (*component * reference).min(reference);
+        }
+
+        let [x, y, z] = components;
+        Xyz::new(x, y, z)
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for Spectral<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_srgb()?.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blue_and_yellow_mix_to_green() -> Result<()> {
+        let blue = Spectral::from_rgb(&Rgb::<f64>::new(0.0, 0.1, 0.9)?)?;
+        let yellow = Spectral::from_rgb(&Rgb::<f64>::new(0.9, 0.8, 0.0)?)?;
+
+        let mixed = blue.mix(&yellow)?.to_rgb()?;
+        assert!(mixed.green() > mixed.red());
+        assert!(mixed.green() > mixed.blue());
+        Ok(())
+    }
+
+    #[test]
+    fn flat_reflectance_is_neutral_grey() -> Result<()> {
+        let grey = Spectral::<f64>::flat(0.5)?.to_lab()?;
+
+        assert!(grey.chroma() < 2.0, "chroma was {}", grey.chroma());
+        assert!(grey.lightness() > 60.0 && grey.lightness() < 90.0);
+        Ok(())
+    }
+
+    #[test]
+    fn mixing_multiplies_reflectances() -> Result<()> {
+        let mixed = Spectral::<f64>::flat(0.5)?.mix(&Spectral::flat(0.4)?)?;
+
+        assert!(mixed.reflectance().iter().all(|r| (r - 0.2).abs() < 1e-12));
+        Ok(())
+    }
+}
@@ -1,18 +1,45 @@
 //! sRGB colour representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
     error::{
-        ColourParsingError, Result, component_to_u8, format_terminal_color, parse_hex_component, safe_constant,
-        u8_to_component, validate_interpolation_factor, validate_unit_component,
+        ChromaticError, ColourParsingError, Result, clamp_unit_component, component_to_u8, component_to_u16,
+        format_terminal_color, parse_css_fraction, parse_css_function, parse_hex_component, safe_constant, u8_to_component,
+        u16_to_component, validate_interpolation_factor, validate_unit_component,
     },
+    named::CSS_NAMED_COLOURS,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, SrgbAlpha, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
+/// Precision of the sRGB transfer function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GammaPrecision {
+    /// Evaluate the transfer function exactly using `powf`.
+    Exact,
+    /// Evaluate a `powf`-free approximation of the transfer function.
+    ///
+    /// The maximum absolute error over [0, 1] is below 5e-5 when encoding and 1e-4 when decoding.
+    Fast,
+}
+
+impl GammaPrecision {
+    /// The precision used by default conversions, selected by the `fast-gamma` feature.
+    #[cfg(not(feature = "fast-gamma"))]
+    pub const DEFAULT: Self = Self::Exact;
+
+    /// The precision used by default conversions, selected by the `fast-gamma` feature.
+    #[cfg(feature = "fast-gamma")]
+    pub const DEFAULT: Self = Self::Fast;
+}
+
 /// sRGB colour representation.
 ///
 /// sRGB is a standard RGB color space widely used in digital displays, image formats, and web content.
@@ -134,6 +161,7 @@ impl<T: Float + Send + Sync> Srgb<T> {
     ///
     /// This converts a linear RGB value to an sRGB value using the standard
     /// piecewise encoding function specified in the sRGB standard.
+    /// The default precision is exact, or fast when the `fast-gamma` feature is enabled.
     ///
     /// # Arguments
     ///
@@ -143,17 +171,14 @@ impl<T: Float + Send + Sync> Srgb<T> {
     ///
     /// Returns an error if mathematical operations fail during encoding.
     pub fn gamma_encode(linear: T) -> Result<T> {
-        Ok(if linear <= safe_constant(0.003_130_8)? {
-            safe_constant::<f64, T>(12.92)? * linear
-        } else {
-            safe_constant::<f64, T>(1.055)? * linear.powf(safe_constant(1.0 / 2.4)?) - safe_constant(0.055)?
-        })
+        Self::gamma_encode_with(linear, GammaPrecision::DEFAULT)
     }
 
     /// Apply the standard sRGB gamma decoding to an sRGB component.
     ///
     /// This converts an sRGB value to a linear RGB value using the standard
     /// piecewise decoding function specified in the sRGB standard.
+    /// The default precision is exact, or fast when the `fast-gamma` feature is enabled.
     ///
     /// # Arguments
     ///
@@ -163,12 +188,156 @@ impl<T: Float + Send + Sync> Srgb<T> {
     ///
     /// Returns an error if mathematical operations fail during decoding.
     pub fn gamma_decode(srgb: T) -> Result<T> {
-        Ok(if srgb <= safe_constant::<f64, T>(0.04045)? {
-            srgb / safe_constant(12.92)?
-        } else {
-            ((srgb + safe_constant(0.055)?) / safe_constant(1.055)?).powf(safe_constant(2.4)?)
+        Self::gamma_decode_with(srgb, GammaPrecision::DEFAULT)
+    }
+
+    /// Apply the standard sRGB gamma encoding to a linear component with the given precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `linear` - Linear RGB component value in range [0, 1]
+    /// * `precision` - Whether to evaluate the transfer function exactly or approximately
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mathematical operations fail during encoding.
+    pub fn gamma_encode_with(linear: T, precision: GammaPrecision) -> Result<T> {
+        if linear <= safe_constant(0.003_130_8)? {
+            return Ok(safe_constant::<f64, T>(12.92)? * linear);
+        }
+
+        Ok(match precision {
+            GammaPrecision::Exact => {
+                safe_constant::<f64, T>(1.055)? * linear.powf(safe_constant(1.0 / 2.4)?) - safe_constant(0.055)?
+            }
+            GammaPrecision::Fast => {
+                // Fit of x^(5/12) using repeated square roots, which avoids `powf`
+                let s1 = linear.sqrt();
+                let s2 = s1.sqrt();
+                let s3 = s2.sqrt();
+                let encoded = safe_constant::<f64, T>(0.654_032_43)? * s1 + safe_constant::<f64, T>(0.688_598_45)? * s2
+                    - safe_constant::<f64, T>(0.318_374_32)? * s3
+                    - safe_constant::<f64, T>(0.020_196_30)? * linear
+                    - safe_constant::<f64, T>(0.004_091_83)?;
+                encoded.max(T::zero()).min(T::one())
+            }
+        })
+    }
+
+    /// Apply the standard sRGB gamma decoding to an sRGB component with the given precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `srgb` - sRGB component value in range [0, 1]
+    /// * `precision` - Whether to evaluate the transfer function exactly or approximately
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mathematical operations fail during decoding.
+    pub fn gamma_decode_with(srgb: T, precision: GammaPrecision) -> Result<T> {
+        if srgb <= safe_constant::<f64, T>(0.04045)? {
+            return Ok(srgb / safe_constant(12.92)?);
+        }
+
+        Ok(match precision {
+            GammaPrecision::Exact => ((srgb + safe_constant(0.055)?) / safe_constant(1.055)?).powf(safe_constant(2.4)?),
+            GammaPrecision::Fast => {
+                // Quartic fit of the power segment, evaluated with Horner's method
+                let linear = (((safe_constant::<f64, T>(-0.088_814_94)? * srgb + safe_constant(0.473_374_00)?) * srgb
+                    + safe_constant(0.591_742_09)?)
+                    * srgb
+                    + safe_constant(0.022_281_38)?)
+                    * srgb
+                    + safe_constant(0.001_323_63)?;
+                linear.max(T::zero()).min(T::one())
+            }
         })
     }
+
+    /// Parse a colour from CSS notation.
+    ///
+    /// Accepts hex strings (e.g. `#ff0000`) and `rgb()` functional notation with channels given either as
+    /// numbers in [0, 255] or percentages (e.g. `rgb(255, 0, 0)` or `rgb(100%, 0%, 0%)`).
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - The CSS colour string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is malformed or any channel is out of range.
+    pub fn from_css(css: &str) -> Result<Self> {
+        let css = css.trim();
+        if css.starts_with('#') {
+            return Self::from_hex(css);
+        }
+
+        let channel = |value: &str, component_name: &str| -> Result<T> {
+            let (number, max) = value
+                .strip_suffix('%')
+                .map_or((value, 255.0), |percentage| (percentage, 100.0));
+            parse_css_fraction(number, component_name, max)
+        };
+
+        let [red, green, blue] = parse_css_function(css, "rgb")?;
+        Self::new(channel(red, "red")?, channel(green, "green")?, channel(blue, "blue")?)
+    }
+
+    /// Look up a CSS named colour (e.g. `cornflowerblue`), ignoring case and surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The colour name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not one of the CSS named colours.
+    pub fn from_name(name: &str) -> Result<Self> {
+        let name = name.trim();
+        let (_, bytes) = CSS_NAMED_COLOURS
+            .iter()
+            .find(|(named, _)| named.eq_ignore_ascii_case(name))
+            .ok_or_else(|| ColourParsingError::InvalidFormat(format!("Unknown colour name '{name}'")))?;
+
+        Self::from_bytes(*bytes)
+    }
+
+    /// Find the CSS named colour closest to this colour, and its CIEDE2000 difference from it.
+    ///
+    /// Where aliases share a value (e.g. `gray` and `grey`) the alphabetically first name is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conversion to Lab or the colour difference calculation fails.
+    pub fn nearest_name(&self) -> Result<(&'static str, T)> {
+        let lab = self.to_lab()?;
+
+        let mut nearest = (CSS_NAMED_COLOURS[0].0, T::infinity());
+        for (name, bytes) in CSS_NAMED_COLOURS {
+            let delta_e = lab.delta_e2000(&Self::from_bytes(bytes)?.to_lab()?)?;
+            if delta_e < nearest.1 {
+                nearest = (name, delta_e);
+            }
+        }
+
+        Ok(nearest)
+    }
+
+    /// Calculate the WCAG 2.1 relative luminance of the colour.
+    ///
+    /// The channels are gamma-decoded exactly, regardless of the `fast-gamma` feature,
+    /// then weighted by 0.2126, 0.7152 and 0.0722.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if gamma decoding or constant conversion fails.
+    pub fn relative_luminance(&self) -> Result<T> {
+        let red = Self::gamma_decode_with(self.red, GammaPrecision::Exact)?;
+        let green = Self::gamma_decode_with(self.green, GammaPrecision::Exact)?;
+        let blue = Self::gamma_decode_with(self.blue, GammaPrecision::Exact)?;
+
+        Ok(red * safe_constant(0.2126)? + green * safe_constant(0.7152)? + blue * safe_constant(0.0722)?)
+    }
 }
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Srgb<T> {
@@ -249,6 +418,37 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Srgb<T> {
         Ok([red, green, blue])
     }
 
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        let scale = safe_constant(65535.0)?;
+        let red = u16_to_component(bytes[0], scale)?;
+        let green = u16_to_component(bytes[1], scale)?;
+        let blue = u16_to_component(bytes[2], scale)?;
+        Self::new(red, green, blue)
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        let scale = safe_constant(65535.0)?;
+        let red = component_to_u16(self.red, "red", scale)?;
+        let green = component_to_u16(self.green, "green", scale)?;
+        let blue = component_to_u16(self.blue, "blue", scale)?;
+
+        Ok([red, green, blue])
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components;
+        Self::new(red, green, blue)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [red, green, blue] = components.map(clamp_unit_component);
+        Self::new(red, green, blue)
+    }
+
     /// Linear interpolate between two sRGB colours.
     /// Note: This performs interpolation in sRGB space, which is not perceptually
     /// uniform. For perceptually uniform interpolation, consider converting to Lab
@@ -265,6 +465,12 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Srgb<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Srgb<T> {
+    const GAMUT: Gamut = Gamut::Srgb;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_srgb()
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
         // For perceptually correct greyscale, use the luminance formula
         // Y = 0.2126*R + 0.7152*G + 0.0722*B (same as in XYZ conversion)
@@ -287,8 +493,67 @@ impl<T: Float + Send + Sync> Convert<T> for Srgb<T> {
         GreyAlpha::new(grey.grey(), T::one())
     }
 
+    #[expect(
+        clippy::similar_names,
+        reason = "f60 and f360 are used for hue calculations. Their names are similar only because they represent different numerical constants."
+    )]
     fn to_hsl(&self) -> Result<Hsl<T>> {
-        self.to_rgb()?.to_hsl()
+        // Work on the gamma-encoded channels directly, as CSS does
+        let r = self.red;
+        let g = self.green;
+        let b = self.blue;
+
+        let max = r.max(g.max(b));
+        let min = r.min(g.min(b));
+        let delta = max - min;
+
+        // Calculate lightness
+        let lightness = (max + min) / safe_constant(2.0)?;
+
+        // If max equals min, the color is a shade of gray (no hue or saturation)
+        // Compare exactly, so that dark near-greys keep their saturation and round-trip accurately
+        if delta == T::zero() {
+            return Hsl::new(T::zero(), T::zero(), lightness);
+        }
+
+        // Calculate saturation
+        let saturation = if lightness <= safe_constant(0.5)? {
+            delta / (max + min)
+        } else {
+            delta / (safe_constant::<f64, T>(2.0)? - max - min)
+        };
+
+        // Constants
+        let f360 = safe_constant(360.0)?;
+        let f60 = safe_constant(60.0)?;
+
+        // Calculate hue
+        let hue = if (r - max).abs() < T::epsilon() {
+            // Red is max
+            let segment = (g - b) / delta;
+            let base_hue = segment * f60;
+            // If green is less than blue, add 360 degrees
+            if g < b { base_hue + f360 } else { base_hue }
+        } else if (g - max).abs() < T::epsilon() {
+            // Green is max
+            let segment = (b - r) / delta;
+            segment * f60 + safe_constant(120.0)?
+        } else {
+            // Blue is max
+            let segment = (r - g) / delta;
+            segment * f60 + safe_constant(240.0)?
+        };
+
+        // Normalize hue to [0, 360) range
+        let normalized_hue = if hue >= f360 {
+            hue - f360
+        } else if hue < T::zero() {
+            hue + f360
+        } else {
+            hue
+        };
+
+        Hsl::new(normalized_hue, saturation, lightness)
     }
 
     fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
@@ -351,7 +616,127 @@ impl<T: Float + Send + Sync> Convert<T> for Srgb<T> {
 
 impl<T: Float + Send + Sync> Display for Srgb<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let color_string = format_terminal_color(self.red, self.green, self.blue, PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+/// Create from bytes (see `Srgb::from_bytes`).
+impl<T: Float + Send + Sync> TryFrom<[u8; 3]> for Srgb<T> {
+    type Error = ChromaticError;
+
+    fn try_from(bytes: [u8; 3]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Convert to bytes (see `Srgb::to_bytes`).
+impl<T: Float + Send + Sync> TryFrom<Srgb<T>> for [u8; 3] {
+    type Error = ChromaticError;
+
+    fn try_from(colour: Srgb<T>) -> Result<Self> {
+        colour.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes16_round_trip_preserves_sub_byte_precision() -> Result<()> {
+        let bytes = [0x1234, 0xFFFF, 0x0000];
+        let colour = Srgb::<f64>::from_bytes16(bytes)?;
+
+        assert!((colour.red() - f64::from(0x1234_u16) / 65535.0).abs() < 1e-12);
+        assert_eq!(colour.to_bytes16()?, bytes);
+        assert_eq!(colour.to_bytes()?, [0x12, 0xFF, 0x00]);
+        Ok(())
+    }
+
+    #[test]
+    fn to_bytes_with_error_reports_quantisation_residual() -> Result<()> {
+        let value = (127.0 + 0.4) / 255.0;
+        let colour = Srgb::<f64>::new(value, 0.5, 1.0)?;
+
+        let (bytes, residuals) = colour.to_bytes_with_error()?;
+        assert_eq!(bytes, [127, 128, 255]);
+        assert!((residuals[0] - 0.4 / 255.0).abs() < 1e-12);
+        assert!((residuals[1] + 0.5 / 255.0).abs() < 1e-12);
+        assert!(residuals[2].abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn fast_gamma_stays_within_documented_error_bounds() -> Result<()> {
+        let steps = 10_000_u32;
+        for i in 0..=steps {
+            let x = f64::from(i) / f64::from(steps);
+
+            let encode_error =
+                Srgb::gamma_encode_with(x, GammaPrecision::Fast)? - Srgb::gamma_encode_with(x, GammaPrecision::Exact)?;
+            let decode_error =
+                Srgb::gamma_decode_with(x, GammaPrecision::Fast)? - Srgb::gamma_decode_with(x, GammaPrecision::Exact)?;
+            assert!(encode_error.abs() < 5e-5, "encode error {encode_error} at {x}");
+            assert!(decode_error.abs() < 1e-4, "decode error {decode_error} at {x}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_accepts_hex_and_rgb_forms() -> Result<()> {
+        for css in ["#ff0000", "rgb(255, 0, 0)", "rgb(100%, 0%, 0%)", "  RGB( 255 0 0 ) "] {
+            let colour = Srgb::<f64>::from_css(css)?;
+            assert_eq!(colour.components().map(f64::to_bits), [1.0, 0.0, 0.0].map(f64::to_bits));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_reports_malformed_input() {
+        for css in [
+            "rgb(255, 0)",
+            "rgb(256, 0, 0)",
+            "rgb(red, 0, 0)",
+            "rgba(255, 0, 0)",
+            "rgb(255, 0, 0",
+        ] {
+            assert!(matches!(Srgb::<f64>::from_css(css), Err(ChromaticError::ColourParsing(_))));
+        }
+    }
+
+    #[test]
+    fn from_name_is_case_insensitive() -> Result<()> {
+        assert_eq!(Srgb::<f64>::from_name("red")?.to_hex()?, "#FF0000");
+        assert_eq!(Srgb::<f64>::from_name(" RebeccaPurple ")?.to_bytes()?, [102, 51, 153]);
+        assert_eq!(Srgb::<f64>::from_name("cornflowerblue")?.to_bytes()?, [100, 149, 237]);
+        assert!(matches!(
+            Srgb::<f64>::from_name("notacolour"),
+            Err(ChromaticError::ColourParsing(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn nearest_name_finds_close_named_colour() -> Result<()> {
+        let (name, delta_e) = Srgb::<f64>::from_hex("#ff0001")?.nearest_name()?;
+
+        assert_eq!(name, "red");
+        assert!(delta_e < 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn byte_arrays_convert_to_and_from_srgb() -> Result<()> {
+        let colour: Srgb<f64> = [255, 51, 0].try_into()?;
+
+        assert_eq!(colour.components().map(f64::to_bits), [1.0, 0.2, 0.0].map(f64::to_bits));
+        let bytes: [u8; 3] = colour.try_into()?;
+        assert_eq!(bytes, [255, 51, 0]);
+        Ok(())
+    }
+}
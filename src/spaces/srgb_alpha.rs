@@ -1,13 +1,18 @@
 //! sRGB colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
-    error::{Result, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    error::{ChromaticError, Result, component_to_u16, safe_constant, validate_unit_component},
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, Xyz, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// sRGB with alpha channel.
@@ -162,5 +167,47 @@ impl<T: Float + Send + Sync> SrgbAlpha<T> {
 }
 
 impl_transparent_colour!(SrgbAlpha<T>, Srgb<T>, 3);
-impl_transparent_convert!(SrgbAlpha<T>, Srgb<T>);
+impl_transparent_convert!(SrgbAlpha<T>, Srgb<T>, {
+    /// Format as CSS `rgba()` functional notation, with 8-bit channels and alpha rounded to 3 decimal places
+    /// (e.g. `rgba(255, 0, 0, 0.5)`).
+    fn to_css_string(&self) -> Result<String> {
+        let [red, green, blue] = self.colour().to_bytes()?;
+        let alpha = component_to_u16(self.alpha(), "alpha", safe_constant(1000.0)?)?;
+        Ok(format!("rgba({red}, {green}, {blue}, {})", f64::from(alpha) / 1000.0))
+    }
+});
 impl_transparent_display!(SrgbAlpha<T>);
+impl_transparent_alpha!(SrgbAlpha<T>);
+
+/// Create from bytes (see `SrgbAlpha::from_bytes`).
+impl<T: Float + Send + Sync> TryFrom<[u8; 4]> for SrgbAlpha<T> {
+    type Error = ChromaticError;
+
+    fn try_from(bytes: [u8; 4]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Convert to bytes (see `SrgbAlpha::to_bytes`).
+impl<T: Float + Send + Sync> TryFrom<SrgbAlpha<T>> for [u8; 4] {
+    type Error = ChromaticError;
+
+    fn try_from(colour: SrgbAlpha<T>) -> Result<Self> {
+        colour.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_arrays_convert_to_and_from_srgb_alpha() -> Result<()> {
+        let colour: SrgbAlpha<f64> = [255, 51, 0, 102].try_into()?;
+
+        assert_eq!(colour.components().map(f64::to_bits), [1.0, 0.2, 0.0, 0.4].map(f64::to_bits));
+        let bytes: [u8; 4] = colour.try_into()?;
+        assert_eq!(bytes, [255, 51, 0, 102]);
+        Ok(())
+    }
+}
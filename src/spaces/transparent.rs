@@ -97,6 +97,75 @@ macro_rules! impl_transparent_colour {
                 Ok(result)
             }
 
+            fn from_bytes16(bytes: [u16; $base_components + 1]) -> Result<Self> {
+                let scale = $crate::error::safe_constant(65535.0)?;
+
+                // Extract base colour values
+                let mut base_bytes = [0_u16; $base_components];
+                for i in 0..$base_components {
+                    base_bytes[i] = bytes[i];
+                }
+
+                let colour = <$base>::from_bytes16(base_bytes)?;
+                let alpha = $crate::error::u16_to_component(bytes[$base_components], scale)?;
+
+                Self::new_colour_with_alpha(colour, alpha)
+            }
+
+            fn to_bytes16(self) -> Result<[u16; $base_components + 1]> {
+                let scale = $crate::error::safe_constant(65535.0)?;
+
+                let base_bytes = self.colour().to_bytes16()?;
+                let alpha = $crate::error::component_to_u16(self.alpha(), "alpha", scale)?;
+
+                // Create result array
+                let mut result = [0_u16; $base_components + 1];
+
+                // Copy base colour values
+                for i in 0..$base_components {
+                    result[i] = base_bytes[i];
+                }
+
+                // Add alpha value
+                result[$base_components] = alpha;
+
+                Ok(result)
+            }
+
+            fn components(&self) -> [T; $base_components + 1] {
+                let base_components = self.colour().components();
+
+                // Append alpha to the base colour components
+                let mut result = [self.alpha(); $base_components + 1];
+                for i in 0..$base_components {
+                    result[i] = base_components[i];
+                }
+
+                result
+            }
+
+            fn from_components(components: [T; $base_components + 1]) -> Result<Self> {
+                // Extract base colour components
+                let mut base_components = [T::zero(); $base_components];
+                for i in 0..$base_components {
+                    base_components[i] = components[i];
+                }
+
+                let colour = <$base>::from_components(base_components)?;
+                Self::new_colour_with_alpha(colour, components[$base_components])
+            }
+
+            fn from_components_clamped(components: [T; $base_components + 1]) -> Result<Self> {
+                let mut base_components = [T::zero(); $base_components];
+                base_components.copy_from_slice(&components[..$base_components]);
+
+                let colour = <$base>::from_components_clamped(base_components)?;
+                Self::new_colour_with_alpha(
+                    colour,
+                    $crate::error::clamp_unit_component(components[$base_components]),
+                )
+            }
+
             fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
                 $crate::error::validate_interpolation_factor(t)?;
 
@@ -113,10 +182,23 @@ macro_rules! impl_transparent_colour {
 }
 
 /// Macro to implement the `Convert` trait for transparent colour types.
+/// An optional trailing block holds additional or overriding trait methods.
 #[macro_export]
 macro_rules! impl_transparent_convert {
-    ($type:ty, $base:ty) => {
+    ($type:ty, $base:ty $(, { $($methods:tt)* })?) => {
         impl<T: Float + Send + Sync> Convert<T> for $type {
+            const GAMUT: $crate::traits::Gamut = <$base as Convert<T>>::GAMUT;
+            const HAS_ALPHA: bool = true;
+
+            fn from_rgb_alpha(colour: &RgbAlpha<T>) -> $crate::error::Result<Self> {
+                let base = <$base>::from_rgb_alpha(colour)?;
+                Self::new_colour_with_alpha(base, colour.alpha())
+            }
+
+            fn from_lab(colour: &Lab<T>) -> $crate::error::Result<Self> {
+                Self::new_colour_with_alpha(<$base>::from_lab(colour)?, T::one())
+            }
+
             fn to_grey(&self) -> $crate::error::Result<Grey<T>> {
                 self.colour().to_grey()
             }
@@ -179,6 +261,8 @@ macro_rules! impl_transparent_convert {
                 let xyz = self.colour().to_xyz()?;
                 XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), self.alpha())
             }
+
+            $($($methods)*)?
         }
     };
 }
@@ -189,7 +273,29 @@ macro_rules! impl_transparent_display {
     ($type:ty) => {
         impl<T: Float + Send + Sync> Display for $type {
             fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
-                write!(fmt, "{}", self.colour())
+                if fmt.alternate() {
+                    write!(fmt, "{:#}", self.colour())
+                } else {
+                    write!(fmt, "{}", self.to_hex()?)
+                }
+            }
+        }
+    };
+}
+
+/// Macro to implement the `Alpha` trait for transparent colour types.
+#[macro_export]
+macro_rules! impl_transparent_alpha {
+    ($type:ty) => {
+        impl<T: Float + Send + Sync> Alpha<T> for $type {
+            fn alpha(&self) -> T {
+                self.alpha
+            }
+
+            fn set_alpha(&mut self, alpha: T) -> $crate::error::Result<()> {
+                $crate::error::validate_unit_component(alpha, "alpha")?;
+                self.alpha = alpha;
+                Ok(())
             }
         }
     };
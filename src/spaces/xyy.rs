@@ -0,0 +1,311 @@
+//! CIE xyY colour representation.
+//!
+//! xyY separates a colour into its chromaticity (x, y), the coordinates plotted on the CIE 1931 chromaticity
+//! diagram, and its luminance Y, which is the same Y as in `Xyz`.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::Float;
+
+use crate::{
+    config::PRINT_BLOCK,
+    error::{Result, clamp_unit_component, format_terminal_color, validate_interpolation_factor, validate_unit_component},
+    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    traits::{Colour, Convert, Gamut},
+};
+
+/// CIE xyY colour representation.
+#[derive(Debug, Clone, Copy)]
+pub struct XyY<T: Float + Send + Sync> {
+    /// x chromaticity coordinate in range [0, 1].
+    x: T,
+    /// y chromaticity coordinate in range [0, 1].
+    y: T,
+    /// Luminance Y in range [0, 1].
+    big_y: T,
+}
+
+impl<T: Float + Send + Sync> XyY<T> {
+    /// Create a new `XyY` instance with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x chromaticity coordinate, must be in range [0, 1]
+    /// * `y` - The y chromaticity coordinate, must be in range [0, 1]
+    /// * `big_y` - The luminance Y, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside the range [0, 1].
+    pub fn new(x: T, y: T, big_y: T) -> Result<Self> {
+        validate_unit_component(x, "x")?;
+        validate_unit_component(y, "y")?;
+        validate_unit_component(big_y, "Y")?;
+
+        Ok(Self { x, y, big_y })
+    }
+
+    /// Get the `x` chromaticity coordinate.
+    pub const fn x(&self) -> T {
+        self.x
+    }
+
+    /// Get the `y` chromaticity coordinate.
+    pub const fn y(&self) -> T {
+        self.y
+    }
+
+    /// Get the `big_y` component (luminance Y).
+    pub const fn big_y(&self) -> T {
+        self.big_y
+    }
+
+    /// Set the `x` chromaticity coordinate with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The new x value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_x(&mut self, x: T) -> Result<()> {
+        validate_unit_component(x, "x")?;
+        self.x = x;
+        Ok(())
+    }
+
+    /// Set the `y` chromaticity coordinate with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The new y value, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_y(&mut self, y: T) -> Result<()> {
+        validate_unit_component(y, "y")?;
+        self.y = y;
+        Ok(())
+    }
+
+    /// Set the `big_y` component (luminance Y) with validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `big_y` - The new luminance, must be in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    pub fn set_big_y(&mut self, big_y: T) -> Result<()> {
+        validate_unit_component(big_y, "Y")?;
+        self.big_y = big_y;
+        Ok(())
+    }
+
+    /// Create an `XyY` colour from an XYZ colour.
+    ///
+    /// Black (X + Y + Z = 0) has no chromaticity, so it is given the chromaticity of the D65 reference white.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - The XYZ colour to convert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn from_xyz(xyz: &Xyz<T>) -> Result<Self> {
+        let sum = xyz.x() + xyz.y() + xyz.z();
+        if sum <= T::zero() {
+            let white = Self::from_xyz(&Xyz::d65_reference_white()?)?;
+            return Self::new(white.x, white.y, T::zero());
+        }
+
+        Self::new(
+            clamp_unit_component(xyz.x() / sum),
+            clamp_unit_component(xyz.y() / sum),
+            xyz.y(),
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Colour<T, 3> for XyY<T> {
+    fn from_hex(hex: &str) -> Result<Self> {
+        Srgb::from_hex(hex)?.to_xyy()
+    }
+
+    fn to_hex(&self) -> Result<String> {
+        self.to_srgb()?.to_hex()
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Result<Self> {
+        Srgb::from_bytes(bytes)?.to_xyy()
+    }
+
+    fn to_bytes(self) -> Result<[u8; 3]> {
+        self.to_srgb()?.to_bytes()
+    }
+
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_xyy()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.x, self.y, self.big_y]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [x, y, big_y] = components;
+        Self::new(x, y, big_y)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [x, y, big_y] = components.map(clamp_unit_component);
+        Self::new(x, y, big_y)
+    }
+
+    /// Linear interpolate between two xyY colours.
+    fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
+        validate_interpolation_factor(t)?;
+
+        Self::new(
+            lhs.x * (T::one() - t) + rhs.x * t,
+            lhs.y * (T::one() - t) + rhs.y * t,
+            lhs.big_y * (T::one() - t) + rhs.big_y * t,
+        )
+    }
+}
+
+impl<T: Float + Send + Sync> Convert<T> for XyY<T> {
+    const GAMUT: Gamut = Gamut::Visible;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_xyy()
+    }
+
+    fn to_xyy(&self) -> Result<Self> {
+        Ok(*self)
+    }
+
+    fn to_grey(&self) -> Result<Grey<T>> {
+        self.to_xyz()?.to_grey()
+    }
+
+    fn to_grey_alpha(&self) -> Result<GreyAlpha<T>> {
+        let grey = self.to_grey()?;
+        GreyAlpha::new(grey.grey(), T::one())
+    }
+
+    fn to_hsl(&self) -> Result<Hsl<T>> {
+        self.to_rgb()?.to_hsl()
+    }
+
+    fn to_hsl_alpha(&self) -> Result<HslAlpha<T>> {
+        let hsl = self.to_hsl()?;
+        HslAlpha::new(hsl.hue(), hsl.saturation(), hsl.lightness(), T::one())
+    }
+
+    fn to_hsv(&self) -> Result<Hsv<T>> {
+        self.to_rgb()?.to_hsv()
+    }
+
+    fn to_hsv_alpha(&self) -> Result<HsvAlpha<T>> {
+        let hsv = self.to_hsv()?;
+        HsvAlpha::new(hsv.hue(), hsv.saturation(), hsv.value(), T::one())
+    }
+
+    fn to_lab(&self) -> Result<Lab<T>> {
+        self.to_xyz()?.to_lab()
+    }
+
+    fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
+        let lab = self.to_lab()?;
+        LabAlpha::new(lab.lightness(), lab.a_star(), lab.b_star(), T::one())
+    }
+
+    fn to_rgb(&self) -> Result<Rgb<T>> {
+        self.to_xyz()?.to_rgb()
+    }
+
+    fn to_rgb_alpha(&self) -> Result<RgbAlpha<T>> {
+        let rgb = self.to_rgb()?;
+        RgbAlpha::new(rgb.red(), rgb.green(), rgb.blue(), T::one())
+    }
+
+    fn to_srgb(&self) -> Result<Srgb<T>> {
+        self.to_xyz()?.to_srgb()
+    }
+
+    fn to_srgb_alpha(&self) -> Result<SrgbAlpha<T>> {
+        let srgb = self.to_srgb()?;
+        SrgbAlpha::new(srgb.red(), srgb.green(), srgb.blue(), T::one())
+    }
+
+    /// Convert to XYZ. A y coordinate of 0 has no defined luminance scale, so it converts to black.
+    fn to_xyz(&self) -> Result<Xyz<T>> {
+        if self.y <= T::zero() {
+            return Xyz::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let scale = self.big_y / self.y;
+        Xyz::new(self.x * scale, self.big_y, (T::one() - self.x - self.y) * scale)
+    }
+
+    fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>> {
+        let xyz = self.to_xyz()?;
+        XyzAlpha::new(xyz.x(), xyz.y(), xyz.z(), T::one())
+    }
+}
+
+impl<T: Float + Send + Sync> Display for XyY<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
+        let srgb = self.to_srgb()?;
+        let color_string = format_terminal_color(srgb.red(), srgb.green(), srgb.blue(), PRINT_BLOCK)?;
+        write!(fmt, "{color_string}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn d65_white_has_standard_chromaticity() -> Result<()> {
+        let white = XyY::from_xyz(&Xyz::<f64>::d65_reference_white()?)?;
+
+        assert!((white.x() - 0.3127).abs() < 1e-4);
+        assert!((white.y() - 0.3290).abs() < 1e-4);
+        assert!((white.big_y() - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn black_takes_the_white_point_chromaticity() -> Result<()> {
+        let black = XyY::from_xyz(&Xyz::<f64>::new(0.0, 0.0, 0.0)?)?;
+
+        assert!((black.x() - 0.3127).abs() < 1e-4 && (black.y() - 0.3290).abs() < 1e-4);
+        assert!(black.big_y().abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn xyz_round_trips_through_xyy() -> Result<()> {
+        let xyz = Xyz::<f64>::new(0.3, 0.4, 0.5)?;
+
+        let restored = XyY::from_xyz(&xyz)?.to_xyz()?;
+        for (value, expected) in restored.components().iter().zip(xyz.components()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        Ok(())
+    }
+}
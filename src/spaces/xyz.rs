@@ -2,16 +2,37 @@
 //! The XYZ colour space is a device-independent colour space defined by the CIE (International Commission on Illumination).
 //! It was created to be a standard reference space for mapping human colour perception.
 
+use alloc::{format, string::String};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     config::PRINT_BLOCK,
-    error::{Result, format_terminal_color, safe_constant, validate_interpolation_factor, validate_unit_component},
+    error::{
+        NumericError, Result, clamp_component_range, clamp_unit_component, format_terminal_color, safe_constant,
+        validate_component_range, validate_interpolation_factor, validate_unit_component,
+    },
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyzAlpha},
-    traits::{Colour, Convert},
+    traits::{Colour, Convert, Gamut},
 };
 
+/// Upper bound for the X and Z components, which exceed 1 for common reference whites (e.g. D65 Z ≈ 1.09).
+const MAX_XZ: f64 = 2.0;
+
+/// Lowest blackbody temperature accepted by `Xyz::from_kelvin`, in kelvin.
+const MIN_KELVIN: f64 = 1000.0;
+
+/// Highest blackbody temperature accepted by `Xyz::from_kelvin`, in kelvin.
+/// Hotter blackbodies have a Z component above `MAX_XZ` when scaled to Y = 1.
+const MAX_KELVIN: f64 = 25000.0;
+
+/// XYZ (D65) to linear sRGB matrix, the inverse of the RGB to XYZ matrix.
+pub(in crate::spaces) const XYZ_TO_LINEAR_RGB: [[f64; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266_0, 1.876_010_8, 0.041_556_0],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
 /// XYZ colour representation.
 #[derive(Debug, Clone, Copy)]
 pub struct Xyz<T: Float + Send + Sync> {
@@ -31,17 +52,17 @@ impl<T: Float + Send + Sync> Xyz<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The X component, must be in range [0, 1]
-    /// * `y` - The Y component (luminance), must be in range [0, 1]  
-    /// * `z` - The Z component, must be in range [0, 1]
+    /// * `x` - The X component, must be in range [0, 2]
+    /// * `y` - The Y component (luminance), must be in range [0, 1]
+    /// * `z` - The Z component, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if any component is outside the range [0, 1].
+    /// Returns an error if Y is outside the range [0, 1] or if X or Z are outside the range [0, 2].
     pub fn new(x: T, y: T, z: T) -> Result<Self> {
-        validate_unit_component(x, "x")?;
+        validate_component_range(x, "x", T::zero(), safe_constant(MAX_XZ)?)?;
         validate_unit_component(y, "y")?;
-        validate_unit_component(z, "z")?;
+        validate_component_range(z, "z", T::zero(), safe_constant(MAX_XZ)?)?;
 
         Ok(Self { x, y, z })
     }
@@ -65,13 +86,13 @@ impl<T: Float + Send + Sync> Xyz<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The new X value, must be in range [0, 1]
+    /// * `x` - The new X value, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if the value is outside the range [0, 1].
+    /// Returns an error if the value is outside the range [0, 2].
     pub fn set_x(&mut self, x: T) -> Result<()> {
-        validate_unit_component(x, "x")?;
+        validate_component_range(x, "x", T::zero(), safe_constant(MAX_XZ)?)?;
         self.x = x;
         Ok(())
     }
@@ -95,13 +116,13 @@ impl<T: Float + Send + Sync> Xyz<T> {
     ///
     /// # Arguments
     ///
-    /// * `z` - The new Z value, must be in range [0, 1]
+    /// * `z` - The new Z value, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if the value is outside the range [0, 1].
+    /// Returns an error if the value is outside the range [0, 2].
     pub fn set_z(&mut self, z: T) -> Result<()> {
-        validate_unit_component(z, "z")?;
+        validate_component_range(z, "z", T::zero(), safe_constant(MAX_XZ)?)?;
         self.z = z;
         Ok(())
     }
@@ -110,17 +131,17 @@ impl<T: Float + Send + Sync> Xyz<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The X component, must be in range [0, 1]
+    /// * `x` - The X component, must be in range [0, 2]
     /// * `y` - The Y component (luminance), must be in range [0, 1]
-    /// * `z` - The Z component, must be in range [0, 1]
+    /// * `z` - The Z component, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if any component is outside the range [0, 1].
+    /// Returns an error if Y is outside the range [0, 1] or if X or Z are outside the range [0, 2].
     pub fn set_components(&mut self, x: T, y: T, z: T) -> Result<()> {
-        validate_unit_component(x, "x")?;
+        validate_component_range(x, "x", T::zero(), safe_constant(MAX_XZ)?)?;
         validate_unit_component(y, "y")?;
-        validate_unit_component(z, "z")?;
+        validate_component_range(z, "z", T::zero(), safe_constant(MAX_XZ)?)?;
 
         self.x = x;
         self.y = y;
@@ -146,6 +167,47 @@ impl<T: Float + Send + Sync> Xyz<T> {
         Self::new(safe_constant(0.96422)?, safe_constant(1.0)?, safe_constant(0.82521)?)
     }
 
+    /// Create an XYZ colour for a blackbody radiator at the given temperature, scaled to Y = 1.
+    ///
+    /// The chromaticity follows the Planckian locus using Krystek's (1985) rational approximation in CIE 1960 UCS,
+    /// which is accurate to within 1e-4 in `u` and `v` up to 15000 K and remains close to the locus beyond it.
+    /// Note that 6504 K lies on the locus, slightly below the D65 white point.
+    ///
+    /// Temperatures are capped at 25000 K: hotter blackbodies are bluer than the valid Z range allows at Y = 1,
+    /// and the locus barely moves beyond that point.
+    ///
+    /// # Arguments
+    ///
+    /// * `temp` - The colour temperature in kelvin, must be in range [1000, 25000]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the temperature is outside the range [1000, 25000], or if constant conversion fails.
+    pub fn from_kelvin(temp: T) -> Result<Self> {
+        if !(temp >= safe_constant(MIN_KELVIN)? && temp <= safe_constant(MAX_KELVIN)?) {
+            return Err(NumericError::InvalidMathOperation(format!(
+                "temperature {} K is outside the supported range [{MIN_KELVIN}, {MAX_KELVIN}]",
+                temp.to_f64().unwrap_or(f64::NAN)
+            ))
+            .into());
+        }
+
+        // Quadratic in the temperature, evaluated with Horner's method
+        let quadratic = |c0: f64, c1: f64, c2: f64| -> Result<T> {
+            Ok((safe_constant::<f64, T>(c2)? * temp + safe_constant(c1)?) * temp + safe_constant(c0)?)
+        };
+        let u = quadratic(0.860_117_757, 1.541_182_54e-4, 1.286_412_12e-7)? / quadratic(1.0, 8.424_202_35e-4, 7.081_451_63e-7)?;
+        let v =
+            quadratic(0.317_398_726, 4.228_062_45e-5, 4.204_816_91e-8)? / quadratic(1.0, -2.897_418_16e-5, 1.614_560_53e-7)?;
+
+        // CIE 1960 (u, v) to CIE 1931 (x, y)
+        let denominator = safe_constant::<f64, T>(2.0)? * u - safe_constant::<f64, T>(8.0)? * v + safe_constant(4.0)?;
+        let x = safe_constant::<f64, T>(3.0)? * u / denominator;
+        let y = safe_constant::<f64, T>(2.0)? * v / denominator;
+
+        Self::new(x / y, T::one(), (T::one() - x - y) / y)
+    }
+
     /// Get XYZ values relative to D65 reference white.
     /// Returns (X/Xn, Y/Yn, Z/Zn)
     ///
@@ -157,6 +219,131 @@ impl<T: Float + Send + Sync> Xyz<T> {
         Ok((self.x / white.x, self.y / white.y, self.z / white.z))
     }
 
+    /// Create an XYZ colour from unbounded (HDR) components using Reinhard tone mapping.
+    ///
+    /// The luminance is compressed with `Y / (1 + Y)` and the X and Z components are scaled by the
+    /// same factor to preserve chromaticity. If X or Z would still exceed their valid range, the
+    /// shared factor is reduced further rather than clamping a single component.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The X component, must be non-negative
+    /// * `y` - The Y component (luminance), must be non-negative
+    /// * `z` - The Z component, must be non-negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is negative or not finite.
+    pub fn from_hdr_reinhard(x: T, y: T, z: T) -> Result<Self> {
+        Self::tone_map_components(x, y, z, |luminance| luminance / (T::one() + luminance))
+    }
+
+    /// Create an XYZ colour from unbounded (HDR) components using the ACES filmic tone curve.
+    ///
+    /// This uses the Narkowicz fit of the ACES reference rendering transform, applied to the luminance
+    /// with X and Z scaled by the same factor. The shared factor is reduced further if any component
+    /// would still fall outside its valid range, so the chromaticity is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The X component, must be non-negative
+    /// * `y` - The Y component (luminance), must be non-negative
+    /// * `z` - The Z component, must be non-negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is negative or not finite.
+    pub fn from_hdr_aces(x: T, y: T, z: T) -> Result<Self> {
+        let a = safe_constant::<f64, T>(2.51)?;
+        let b = safe_constant::<f64, T>(0.03)?;
+        let c = safe_constant::<f64, T>(2.43)?;
+        let d = safe_constant::<f64, T>(0.59)?;
+        let e = safe_constant::<f64, T>(0.14)?;
+
+        Self::tone_map_components(x, y, z, |luminance| {
+            (luminance * (a * luminance + b)) / (luminance * (c * luminance + d) + e)
+        })
+    }
+
+    /// Compress the luminance of this colour using Reinhard tone mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tone mapping calculation fails.
+    pub fn tone_map_reinhard(&self) -> Result<Self> {
+        Self::from_hdr_reinhard(self.x, self.y, self.z)
+    }
+
+    /// Compress the luminance of this colour using the ACES filmic tone curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tone mapping calculation fails.
+    pub fn tone_map_aces(&self) -> Result<Self> {
+        Self::from_hdr_aces(self.x, self.y, self.z)
+    }
+
+    /// Apply a tone curve to the luminance, scaling X and Z by the same factor.
+    fn tone_map_components<F>(x: T, y: T, z: T, curve: F) -> Result<Self>
+    where
+        F: Fn(T) -> T,
+    {
+        validate_component_range(x, "x", T::zero(), T::infinity())?;
+        validate_component_range(y, "y", T::zero(), T::infinity())?;
+        validate_component_range(z, "z", T::zero(), T::infinity())?;
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            return Err(NumericError::InfiniteResult.into());
+        }
+
+        let max_xz = safe_constant::<f64, T>(MAX_XZ)?;
+
+        // Black stays black
+        if y <= T::zero() {
+            return Self::new(x.min(max_xz), T::zero(), z.min(max_xz));
+        }
+
+        // Limit the shared factor so every component stays in range without shifting the chromaticity
+        let mut scale = (curve(y) / y).min(T::one() / y);
+        if x > T::zero() {
+            scale = scale.min(max_xz / x);
+        }
+        if z > T::zero() {
+            scale = scale.min(max_xz / z);
+        }
+
+        Self::new((x * scale).min(max_xz), (y * scale).min(T::one()), (z * scale).min(max_xz))
+    }
+
+    /// Check whether the colour lies within the sRGB gamut, i.e. converts to linear RGB without clamping.
+    ///
+    /// A tolerance of 1e-6 allows for rounding error in the conversion matrices.
+    /// Colours that cannot be converted at all are reported as outside the gamut.
+    #[must_use]
+    pub fn in_srgb_gamut(&self) -> bool {
+        let (Ok(tolerance), Ok(components)) = (safe_constant::<f64, T>(1e-6), self.linear_rgb_unclamped()) else {
+            return false;
+        };
+        let (min, max) = (-tolerance, T::one() + tolerance);
+
+        let components: [T; 3] = components.into();
+        components.iter().all(|&component| component >= min && component <= max)
+    }
+
+    /// Get the linear RGB components of this colour without clamping to [0, 1].
+    /// Returns (R, G, B), where components outside [0, 1] indicate the colour is outside the sRGB gamut.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn linear_rgb_unclamped(&self) -> Result<(T, T, T)> {
+        let mut rgb = [T::zero(); 3];
+        for (channel, [m1, m2, m3]) in rgb.iter_mut().zip(XYZ_TO_LINEAR_RGB) {
+            *channel = self.x * safe_constant(m1)? + self.y * safe_constant(m2)? + self.z * safe_constant(m3)?;
+        }
+
+        Ok(rgb.into())
+    }
+
     /// Calculate perceptual colour difference in XYZ space (simple Euclidean distance).
     /// Note: This is not an ideal colour difference metric - consider using Lab with Delta E metrics for better results.
     pub fn distance(&self, other: &Self) -> T {
@@ -165,6 +352,52 @@ impl<T: Float + Send + Sync> Xyz<T> {
         let dz = self.z - other.z;
         (dx * dx + dy * dy + dz * dz).sqrt()
     }
+
+    /// Convert to Lab relative to the given reference white.
+    ///
+    /// [`Convert::to_lab`] is equivalent to passing the D65 reference white.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - The reference white, which maps to L* = 100 with zero a* and b*
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting Lab components are invalid.
+    pub fn to_lab_with_white(&self, white: &Self) -> Result<Lab<T>> {
+        // Constants for the conversion
+        let epsilon = safe_constant(0.008_856)?; // Intent is 216/24389
+        let kappa = safe_constant::<f64, T>(903.3)?; // Intent is 24389/27
+
+        // Get XYZ values relative to the reference white
+        let (x_r, y_r, z_r) = (self.x / white.x, self.y / white.y, self.z / white.z);
+
+        // Compute f(x), f(y), f(z)
+        let f_x = if x_r > epsilon {
+            x_r.powf(safe_constant(1.0 / 3.0)?)
+        } else {
+            (kappa * x_r + safe_constant(16.0)?) / safe_constant(116.0)?
+        };
+
+        let f_y = if y_r > epsilon {
+            y_r.powf(safe_constant(1.0 / 3.0)?)
+        } else {
+            (kappa * y_r + safe_constant(16.0)?) / safe_constant(116.0)?
+        };
+
+        let f_z = if z_r > epsilon {
+            z_r.powf(safe_constant(1.0 / 3.0)?)
+        } else {
+            (kappa * z_r + safe_constant(16.0)?) / safe_constant(116.0)?
+        };
+
+        // Compute Lab components
+        let l = safe_constant::<f64, T>(116.0)? * f_y - safe_constant(16.0)?;
+        let a = safe_constant::<f64, T>(500.0)? * (f_x - f_y);
+        let b = safe_constant::<f64, T>(200.0)? * (f_y - f_z);
+
+        Lab::new(l, a, b)
+    }
 }
 
 impl<T: Float + Send + Sync> Colour<T, 3> for Xyz<T> {
@@ -192,6 +425,34 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Xyz<T> {
         self.to_srgb()?.to_bytes()
     }
 
+    fn from_bytes16(bytes: [u16; 3]) -> Result<Self> {
+        Srgb::from_bytes16(bytes)?.to_xyz()
+    }
+
+    fn to_bytes16(self) -> Result<[u16; 3]> {
+        self.to_srgb()?.to_bytes16()
+    }
+
+    fn components(&self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn from_components(components: [T; 3]) -> Result<Self> {
+        let [x, y, z] = components;
+        Self::new(x, y, z)
+    }
+
+    fn from_components_clamped(components: [T; 3]) -> Result<Self> {
+        let [x, y, z] = components;
+        let max_xz = safe_constant(MAX_XZ)?;
+
+        Self::new(
+            clamp_component_range(x, T::zero(), max_xz),
+            clamp_unit_component(y),
+            clamp_component_range(z, T::zero(), max_xz),
+        )
+    }
+
     /// Linear interpolate between two XYZ colours.
     /// Note: Prefer Lab for perceptually uniform interpolation.
     fn lerp(lhs: &Self, rhs: &Self, t: T) -> Result<Self> {
@@ -206,6 +467,12 @@ impl<T: Float + Send + Sync> Colour<T, 3> for Xyz<T> {
 }
 
 impl<T: Float + Send + Sync> Convert<T> for Xyz<T> {
+    const GAMUT: Gamut = Gamut::Visible;
+
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self> {
+        colour.to_xyz()
+    }
+
     fn to_grey(&self) -> Result<Grey<T>> {
         // Use the Y component (luminance) for greyscale
         // Clamp to [0, 1] range for Grey
@@ -236,38 +503,7 @@ impl<T: Float + Send + Sync> Convert<T> for Xyz<T> {
     }
 
     fn to_lab(&self) -> Result<Lab<T>> {
-        // Constants for the conversion
-        let epsilon = safe_constant(0.008_856)?; // Intent is 216/24389
-        let kappa = safe_constant::<f64, T>(903.3)?; // Intent is 24389/27
-
-        // Get XYZ values relative to reference white (D65)
-        let (x_r, y_r, z_r) = self.relative_to_white()?;
-
-        // Compute f(x), f(y), f(z)
-        let f_x = if x_r > epsilon {
-            x_r.powf(safe_constant(1.0 / 3.0)?)
-        } else {
-            (kappa * x_r + safe_constant(16.0)?) / safe_constant(116.0)?
-        };
-
-        let f_y = if y_r > epsilon {
-            y_r.powf(safe_constant(1.0 / 3.0)?)
-        } else {
-            (kappa * y_r + safe_constant(16.0)?) / safe_constant(116.0)?
-        };
-
-        let f_z = if z_r > epsilon {
-            z_r.powf(safe_constant(1.0 / 3.0)?)
-        } else {
-            (kappa * z_r + safe_constant(16.0)?) / safe_constant(116.0)?
-        };
-
-        // Compute Lab components
-        let l = safe_constant::<f64, T>(116.0)? * f_y - safe_constant(16.0)?;
-        let a = safe_constant::<f64, T>(500.0)? * (f_x - f_y);
-        let b = safe_constant::<f64, T>(200.0)? * (f_y - f_z);
-
-        Lab::new(l, a, b)
+        self.to_lab_with_white(&Self::d65_reference_white()?)
     }
 
     fn to_lab_alpha(&self) -> Result<LabAlpha<T>> {
@@ -276,16 +512,7 @@ impl<T: Float + Send + Sync> Convert<T> for Xyz<T> {
     }
 
     fn to_rgb(&self) -> Result<Rgb<T>> {
-        // XYZ to linear RGB transformation
-        // Using the inverse of the RGB to XYZ matrix
-        let r =
-            self.x * safe_constant(3.240_454_2)? - self.y * safe_constant(1.537_138_5)? - self.z * safe_constant(0.498_531_4)?;
-
-        let g =
-            -self.x * safe_constant(0.969_266_0)? + self.y * safe_constant(1.876_010_8)? + self.z * safe_constant(0.041_556_0)?;
-
-        let b =
-            self.x * safe_constant(0.055_643_4)? - self.y * safe_constant(0.204_025_9)? + self.z * safe_constant(1.057_225_2)?;
+        let (r, g, b) = self.linear_rgb_unclamped()?;
 
         // Clamp to [0, 1] range
         let clamped_r = r.max(T::zero()).min(T::one());
@@ -328,8 +555,92 @@ impl<T: Float + Send + Sync> Convert<T> for Xyz<T> {
 
 impl<T: Float + Send + Sync> Display for Xyz<T> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+        if !fmt.alternate() {
+            return write!(fmt, "{}", self.to_hex()?);
+        }
+
         let rgb = self.to_rgb()?;
         let color_string = format_terminal_color(rgb.red(), rgb.green(), rgb.blue(), PRINT_BLOCK)?;
         write!(fmt, "{color_string}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_whites_are_valid() -> Result<()> {
+        let d65 = Xyz::<f64>::d65_reference_white()?;
+        let d50 = Xyz::<f64>::d50_reference_white()?;
+
+        assert!((d65.z() - 1.08883).abs() < 1e-12);
+        assert!((d50.x() - 0.96422).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn from_kelvin_6504_is_near_d65_chromaticity() -> Result<()> {
+        let white = Xyz::<f64>::from_kelvin(6504.0)?;
+        let sum = white.x() + white.y() + white.z();
+
+        assert!((white.x() / sum - 0.3127).abs() < 0.002);
+        assert!((white.y() / sum - 0.3290).abs() < 0.006);
+        assert!((white.y() - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn from_kelvin_rejects_temperatures_outside_the_supported_range() -> Result<()> {
+        assert!(Xyz::<f64>::from_kelvin(999.0).is_err());
+        assert!(Xyz::<f64>::from_kelvin(25001.0).is_err());
+        assert!(Xyz::<f64>::from_kelvin(25000.0)?.z() <= MAX_XZ);
+        Ok(())
+    }
+
+    #[test]
+    fn reinhard_compresses_bright_luminance_below_one() -> Result<()> {
+        let mapped = Xyz::<f64>::from_hdr_reinhard(3.8, 4.0, 4.4)?;
+
+        assert!(mapped.y() < 1.0);
+        assert!((mapped.y() - 0.8).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn reinhard_preserves_chromaticity() -> Result<()> {
+        let (x, y, z) = (3.8, 4.0, 4.4);
+        let mapped = Xyz::<f64>::from_hdr_reinhard(x, y, z)?;
+
+        assert!((mapped.x() / mapped.y() - x / y).abs() < 1e-12);
+        assert!((mapped.z() / mapped.y() - z / y).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn reinhard_leaves_dim_values_roughly_unchanged() -> Result<()> {
+        let mapped = Xyz::<f64>::from_hdr_reinhard(0.019, 0.02, 0.022)?;
+
+        assert!((mapped.y() - 0.02).abs() < 1e-3);
+        assert!((mapped.x() - 0.019).abs() < 1e-3);
+        assert!((mapped.z() - 0.022).abs() < 1e-3);
+        Ok(())
+    }
+
+    #[test]
+    fn tone_map_keeps_blue_heavy_colours_in_range() -> Result<()> {
+        let mapped = Xyz::<f64>::from_hdr_aces(1.0, 0.5, 9.0)?;
+
+        assert!(mapped.z() <= MAX_XZ);
+        assert!((mapped.z() / mapped.x() - 9.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn srgb_gamut_check_flags_unrepresentable_xyz() -> Result<()> {
+        assert!(Xyz::<f64>::d65_reference_white()?.in_srgb_gamut());
+        // Mostly luminance with little X or Z needs negative red and blue
+        assert!(!Xyz::<f64>::new(0.1, 0.5, 0.1)?.in_srgb_gamut());
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 //! XYZ colour with transparency representation.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::Float;
-use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::{
     error::{Result, validate_unit_component},
-    impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
+    impl_transparent_alpha, impl_transparent_colour, impl_transparent_convert, impl_transparent_display,
     spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz},
-    traits::{Colour, Convert},
+    traits::{Alpha, Colour, Convert},
 };
 
 /// XYZ with alpha channel.
@@ -24,14 +29,14 @@ impl<T: Float + Send + Sync> XyzAlpha<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The X component, must be in range [0, 1]
+    /// * `x` - The X component, must be in range [0, 2]
     /// * `y` - The Y component (luminance), must be in range [0, 1]
-    /// * `z` - The Z component, must be in range [0, 1]
+    /// * `z` - The Z component, must be in range [0, 2]
     /// * `alpha` - The alpha (transparency) component, must be in range [0, 1]
     ///
     /// # Errors
     ///
-    /// Returns an error if any component is outside the range [0, 1].
+    /// Returns an error if any component is outside its valid range.
     pub fn new(x: T, y: T, z: T, alpha: T) -> Result<Self> {
         validate_unit_component(alpha, "alpha")?;
 
@@ -86,11 +91,11 @@ impl<T: Float + Send + Sync> XyzAlpha<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The new X value, must be in range [0, 1]
+    /// * `x` - The new X value, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if the value is outside the range [0, 1].
+    /// Returns an error if the value is outside the range [0, 2].
     pub fn set_x(&mut self, x: T) -> Result<()> {
         self.colour.set_x(x)
     }
@@ -112,11 +117,11 @@ impl<T: Float + Send + Sync> XyzAlpha<T> {
     ///
     /// # Arguments
     ///
-    /// * `z` - The new Z value, must be in range [0, 1]
+    /// * `z` - The new Z value, must be in range [0, 2]
     ///
     /// # Errors
     ///
-    /// Returns an error if the value is outside the range [0, 1].
+    /// Returns an error if the value is outside the range [0, 2].
     pub fn set_z(&mut self, z: T) -> Result<()> {
         self.colour.set_z(z)
     }
@@ -140,21 +145,18 @@ impl<T: Float + Send + Sync> XyzAlpha<T> {
     ///
     /// # Arguments
     ///
-    /// * `x` - The X component, must be in range [0, 1]
+    /// * `x` - The X component, must be in range [0, 2]
     /// * `y` - The Y component (luminance), must be in range [0, 1]
-    /// * `z` - The Z component, must be in range [0, 1]
+    /// * `z` - The Z component, must be in range [0, 2]
     /// * `alpha` - The alpha component, must be in range [0, 1]
     ///
     /// # Errors
     ///
     /// Returns an error if any component validation fails.
     pub fn set_components(&mut self, x: T, y: T, z: T, alpha: T) -> Result<()> {
-        validate_unit_component(x, "x")?;
-        validate_unit_component(y, "y")?;
-        validate_unit_component(z, "z")?;
         validate_unit_component(alpha, "alpha")?;
 
-        // If all validations pass, update all components
+        // Validate the base colour before updating any components
         self.colour = Xyz::new(x, y, z)?;
         self.alpha = alpha;
         Ok(())
@@ -181,3 +183,4 @@ impl<T: Float + Send + Sync> XyzAlpha<T> {
 impl_transparent_colour!(XyzAlpha<T>, Xyz<T>, 3);
 impl_transparent_convert!(XyzAlpha<T>, Xyz<T>);
 impl_transparent_display!(XyzAlpha<T>);
+impl_transparent_alpha!(XyzAlpha<T>);
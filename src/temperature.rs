@@ -0,0 +1,86 @@
+//! ## Colour Temperature Module
+//!
+//! This module provides a perceptual warm/cool classification of colours based on their CIE L*a*b* hue angle.
+
+use num_traits::Float;
+
+use crate::{
+    error::{Result, safe_constant},
+    spaces::Lab,
+};
+
+/// Perceptual temperature of a colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Temperature {
+    /// Reds, oranges and yellows.
+    Warm,
+    /// Greens, cyans, blues and violets.
+    Cool,
+    /// Near-grey colours with too little chroma to have a temperature.
+    Neutral,
+}
+
+impl Temperature {
+    /// Default chroma below which a colour is classified as neutral.
+    pub const DEFAULT_CHROMA_THRESHOLD: f64 = 10.0;
+
+    /// Lower bound of the warm hue range in degrees (inclusive), covering magenta-reds.
+    const WARM_HUE_START: f64 = -30.0;
+
+    /// Upper bound of the warm hue range in degrees (exclusive), covering yellows.
+    const WARM_HUE_END: f64 = 120.0;
+
+    /// Classify a Lab colour by its hue angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `lab` - The colour to classify
+    /// * `chroma_threshold` - Chroma below which the colour is considered neutral
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    pub fn from_lab<T: Float + Send + Sync>(lab: &Lab<T>, chroma_threshold: T) -> Result<Self> {
+        let (a, b) = (lab.a_star(), lab.b_star());
+
+        if a.hypot(b) < chroma_threshold {
+            return Ok(Self::Neutral);
+        }
+
+        // Hue angle in degrees, in range (-180, 180]
+        let hue = b.atan2(a).to_degrees();
+        if hue >= safe_constant(Self::WARM_HUE_START)? && hue < safe_constant(Self::WARM_HUE_END)? {
+            Ok(Self::Warm)
+        } else {
+            Ok(Self::Cool)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        spaces::Rgb,
+        traits::{Colour, Convert},
+    };
+
+    #[test]
+    fn classifies_red_warm_blue_cool_and_grey_neutral() -> Result<()> {
+        assert_eq!(Rgb::<f64>::from_hex("#ff0000")?.temperature_class()?, Temperature::Warm);
+        assert_eq!(Rgb::<f64>::from_hex("#ffa500")?.temperature_class()?, Temperature::Warm);
+        assert_eq!(Rgb::<f64>::from_hex("#0000ff")?.temperature_class()?, Temperature::Cool);
+        assert_eq!(Rgb::<f64>::from_hex("#00ffff")?.temperature_class()?, Temperature::Cool);
+        assert_eq!(Rgb::<f64>::from_hex("#808080")?.temperature_class()?, Temperature::Neutral);
+        Ok(())
+    }
+
+    #[test]
+    fn chroma_threshold_controls_neutral_classification() -> Result<()> {
+        let muted = Lab::<f64>::new(50.0, 8.0, 4.0)?;
+
+        assert_eq!(Temperature::from_lab(&muted, 10.0)?, Temperature::Neutral);
+        assert_eq!(Temperature::from_lab(&muted, 5.0)?, Temperature::Warm);
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+//! Trait implemented by all colour types with an alpha channel.
+
+use num_traits::Float;
+
+use crate::error::Result;
+
+/// Common trait for colour types with transparency.
+pub trait Alpha<T: Float + Send + Sync>: Clone {
+    /// Get the `alpha` component.
+    fn alpha(&self) -> T;
+
+    /// Set the `alpha` component.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    fn set_alpha(&mut self, alpha: T) -> Result<()>;
+
+    /// Create a copy of the colour with a different `alpha` component.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1].
+    fn with_alpha(&self, alpha: T) -> Result<Self> {
+        let mut colour = self.clone();
+        colour.set_alpha(alpha)?;
+        Ok(colour)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::{HslAlpha, RgbAlpha};
+
+    fn fade<T: Float + Send + Sync, C: Alpha<T>>(colour: &C, alpha: T) -> Result<C> {
+        colour.with_alpha(alpha)
+    }
+
+    #[test]
+    fn generic_fade_sets_alpha_on_any_alpha_space() -> Result<()> {
+        let rgba = RgbAlpha::<f64>::new(1.0, 0.5, 0.0, 1.0)?;
+        let hsla = HslAlpha::<f64>::new(120.0, 0.5, 0.5, 1.0)?;
+
+        let faded_rgba = fade(&rgba, 0.25)?;
+        let faded_hsla = fade(&hsla, 0.25)?;
+        assert!((faded_rgba.alpha() - 0.25).abs() < 1e-12);
+        assert!((faded_hsla.alpha() - 0.25).abs() < 1e-12);
+        assert!((faded_rgba.green() - 0.5).abs() < 1e-12);
+        assert!((faded_hsla.hue() - 120.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn set_alpha_validates_range() -> Result<()> {
+        let mut rgba = RgbAlpha::<f64>::new(1.0, 0.5, 0.0, 1.0)?;
+
+        assert!(rgba.set_alpha(1.5).is_err());
+        assert!(fade(&rgba, -0.1).is_err());
+        rgba.set_alpha(0.0)?;
+        assert!(rgba.alpha().abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! Trait for comparing colours within a tolerance.
+
+use num_traits::Float;
+
+use crate::{
+    spaces::{
+        AdobeRgb, Cmyk, DisplayP3, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, Lchuv, Luv,
+        Oklab, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+    },
+    traits::Colour,
+};
+
+/// Approximate equality of colours, for comparisons after lossy conversion chains.
+pub trait ApproxEq<T: Float + Send + Sync> {
+    /// Check whether every component differs from the corresponding component of `other` by at most `epsilon`.
+    ///
+    /// Components are compared in their native units, so hues are compared in degrees without wrapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The colour to compare against
+    /// * `epsilon` - The maximum absolute difference allowed per component
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool;
+}
+
+/// Macro to implement `ApproxEq` for a colour type by comparing its components.
+macro_rules! impl_approx_eq {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl<T: Float + Send + Sync> ApproxEq<T> for $type<T> {
+                fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+                    self.components()
+                        .into_iter()
+                        .zip(other.components())
+                        .all(|(lhs, rhs)| (lhs - rhs).abs() <= epsilon)
+                }
+            }
+        )+
+    };
+}
+
+impl_approx_eq!(
+    AdobeRgb, Cmyk, DisplayP3, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, Lchuv, Luv, Oklab,
+    Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Result, traits::Convert};
+
+    #[test]
+    fn rgb_survives_lab_round_trip_within_epsilon() -> Result<()> {
+        let colour = Rgb::<f64>::new(0.2, 0.5, 0.8)?;
+
+        let restored = colour.to_lab()?.to_rgb()?;
+        assert!(restored.approx_eq(&colour, 1e-6));
+        Ok(())
+    }
+
+    #[test]
+    fn approx_eq_respects_epsilon_per_component() -> Result<()> {
+        let colour = Hsl::<f64>::new(120.0, 0.5, 0.5)?;
+        let nudged = Hsl::<f64>::new(120.0, 0.5, 0.51)?;
+
+        assert!(colour.approx_eq(&nudged, 0.02));
+        assert!(!colour.approx_eq(&nudged, 0.005));
+        assert!(colour.approx_eq(&colour, 0.0));
+        Ok(())
+    }
+}
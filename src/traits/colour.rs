@@ -1,9 +1,13 @@
 //! Trait implemented by all colour types.
 
+use alloc::{format, string::String, vec, vec::Vec};
+use core::ops::AddAssign;
 use num_traits::Float;
-use std::ops::AddAssign;
 
-use crate::error::{InterpolationError, Result};
+use crate::{
+    error::{ColourParsingError, InterpolationError, Result, component_to_u16, safe_constant, u16_to_component},
+    traits::Convert,
+};
 
 /// Common trait for all colour types.
 pub trait Colour<T: Float + Send + Sync, const N: usize> {
@@ -42,6 +46,195 @@ pub trait Colour<T: Float + Send + Sync, const N: usize> {
     /// Returns an error if conversion fails or components are out of range.
     fn to_bytes(self) -> Result<[u8; N]>;
 
+    /// Create a new colour from a slice of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - A slice of bytes in the same order as [`Colour::from_bytes`], must have length `N`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice length is not `N` or byte conversion fails.
+    fn from_byte_slice(bytes: &[u8]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let array: [u8; N] = bytes.try_into().map_err(|_| ColourParsingError::InvalidComponentCount {
+            expected: N,
+            actual: bytes.len(),
+        })?;
+        Self::from_bytes(array)
+    }
+
+    /// Create a new colour from a packed integer.
+    ///
+    /// Bytes are packed in the same order as hex strings, with the last component in the lowest byte
+    /// (e.g. `0xRRGGBB` for `Rgb`, `0xRRGGBBAA` for `RgbAlpha` and `0xGG` for `Grey`).
+    /// Any higher bytes not used by the colour type are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the colour type has more than 4 components or byte conversion fails.
+    fn from_u32(packed: u32) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let packed_bytes = packed.to_be_bytes();
+        let offset = packed_bytes
+            .len()
+            .checked_sub(N)
+            .ok_or(ColourParsingError::InvalidComponentCount {
+                expected: packed_bytes.len(),
+                actual: N,
+            })?;
+
+        Self::from_bytes(core::array::from_fn(|i| packed_bytes[offset + i]))
+    }
+
+    /// Convert the colour to a packed integer, in the layout described by [`Colour::from_u32`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the colour type has more than 4 components or byte conversion fails.
+    fn to_u32(self) -> Result<u32>
+    where
+        Self: Sized,
+    {
+        if N > 4 {
+            return Err(ColourParsingError::InvalidComponentCount { expected: 4, actual: N }.into());
+        }
+
+        Ok(self
+            .to_bytes()?
+            .iter()
+            .fold(0, |packed, &byte| (packed << 8) | u32::from(byte)))
+    }
+
+    /// Convert the colour to a byte array, also returning the quantization error of each component.
+    ///
+    /// The residual is the original component minus the component recovered from the bytes,
+    /// which can be diffused to neighbouring pixels when dithering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to or from bytes fails.
+    fn to_bytes_with_error(self) -> Result<([u8; N], [T; N])>
+    where
+        Self: Sized,
+    {
+        let components = self.components();
+        let bytes = self.to_bytes()?;
+        let recovered = Self::from_bytes(bytes)?.components();
+
+        let residuals = core::array::from_fn(|i| components[i] - recovered[i]);
+        Ok((bytes, residuals))
+    }
+
+    /// Create a new colour from a 16-bit per channel array.
+    ///
+    /// The default scales each value into [0, 1] and passes the result to [`Colour::from_components`],
+    /// so colour spaces with components outside [0, 1] override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion fails or values are out of range.
+    fn from_bytes16(bytes: [u16; N]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let scale = safe_constant(65535.0)?;
+        let mut components = [T::zero(); N];
+        for (component, value) in components.iter_mut().zip(bytes) {
+            *component = u16_to_component(value, scale)?;
+        }
+        Self::from_components(components)
+    }
+
+    /// Convert the colour to a 16-bit per channel array.
+    ///
+    /// The default scales each of [`Colour::components`] from [0, 1],
+    /// so colour spaces with components outside [0, 1] override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion fails or components are out of range.
+    fn to_bytes16(self) -> Result<[u16; N]>
+    where
+        Self: Sized,
+    {
+        let scale = safe_constant(65535.0)?;
+        let mut bytes = [0; N];
+        for (value, component) in bytes.iter_mut().zip(self.components()) {
+            *value = component_to_u16(component, "component", scale)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Get the components of the colour in their native order and units.
+    ///
+    /// This and the two constructors below are required, which is a breaking change from version 0.1.
+    fn components(&self) -> [T; N];
+
+    /// Create a new colour from an array of components in their native order and units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is outside its valid range.
+    fn from_components(components: [T; N]) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Create a new colour from an array of components, clamping each to its valid range instead of failing.
+    /// Hue components are wrapped into [0, 360) rather than clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if constant conversion fails.
+    fn from_components_clamped(components: [T; N]) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Clamp each component into its own range, then build a new colour from the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - The lower bound of each component, in native order and units
+    /// * `hi` - The upper bound of each component, in native order and units
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any clamped component is outside its valid range for this colour space.
+    fn clamp_each(&self, lo: [T; N], hi: [T; N]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut components = self.components();
+        for ((component, low), high) in components.iter_mut().zip(lo).zip(hi) {
+            *component = component.max(low).min(high);
+        }
+        Self::from_components(components)
+    }
+
+    /// Create a new colour from a slice of components.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - A slice of components in their native order, must have length `N`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice length is not `N` or if any component is outside its valid range.
+    fn try_from_slice(values: &[T]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let components: [T; N] = values.try_into().map_err(|_| ColourParsingError::InvalidComponentCount {
+            expected: N,
+            actual: values.len(),
+        })?;
+        Self::from_components(components)
+    }
+
     /// Linear interpolate between two colours of the same type.
     ///
     /// # Arguments
@@ -62,6 +255,8 @@ pub trait Colour<T: Float + Send + Sync, const N: usize> {
     ///
     /// This method combines multiple colours using their associated weights.
     /// The weights do not need to sum to 1.0 - they will be normalized internally.
+    /// Mixing is performed in the colour's own space, so Lab colours mix perceptually,
+    /// and two colours with equal weights give the same result as `lerp` at 0.5.
     ///
     /// # Arguments
     ///
@@ -214,4 +409,118 @@ pub trait Colour<T: Float + Send + Sync, const N: usize> {
 
         Ok(gradient)
     }
+
+    /// Describe the colour for debugging, showing its space name, sRGB hex and native components.
+    ///
+    /// For example, `Lab { hex: "#FF0000", components: [53.24, 80.09, 67.2] }`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion to sRGB fails.
+    fn inspect(&self) -> Result<String>
+    where
+        Self: Convert<T>,
+    {
+        // Strip the module path and generic parameters from the type name
+        let type_name = core::any::type_name::<Self>();
+        let type_name = type_name.split('<').next().unwrap_or(type_name);
+        let name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        let hex = self.to_srgb()?.to_hex()?;
+        let components: Vec<f64> = self
+            .components()
+            .iter()
+            .map(|component| component.to_f64().unwrap_or(f64::NAN))
+            .collect();
+
+        Ok(format!("{name} {{ hex: \"{hex}\", components: {components:?} }}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::ChromaticError,
+        spaces::{Grey, Rgb, RgbAlpha, Srgb},
+    };
+
+    #[test]
+    fn try_from_slice_rejects_wrong_length() {
+        let result = Rgb::<f64>::try_from_slice(&[0.1, 0.2]);
+
+        assert!(matches!(
+            result,
+            Err(ChromaticError::ColourParsing(ColourParsingError::InvalidComponentCount {
+                expected: 3,
+                actual: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn try_from_slice_builds_from_valid_components() -> Result<()> {
+        let colour = Rgb::<f64>::try_from_slice(&[0.1, 0.2, 0.3])?;
+
+        assert!((colour.red() - 0.1).abs() < 1e-12);
+        assert!((colour.green() - 0.2).abs() < 1e-12);
+        assert!((colour.blue() - 0.3).abs() < 1e-12);
+        assert!(Rgb::<f64>::try_from_slice(&[0.1, 1.5, 0.3]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn from_u32_reads_packed_rgb() -> Result<()> {
+        let red = Rgb::<f64>::from_u32(0x00FF_0000)?;
+
+        assert!((red.red() - 1.0).abs() < 1e-4);
+        assert!(red.green().abs() < 1e-12 && red.blue().abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn u32_round_trips_for_several_layouts() -> Result<()> {
+        for packed in [0x0000_0000, 0x0012_3456, 0x00AB_CDEF, 0x00FF_FFFF] {
+            assert_eq!(Srgb::<f64>::from_u32(packed)?.to_u32()?, packed);
+        }
+        assert_eq!(RgbAlpha::<f64>::from_u32(0x1234_5678)?.to_u32()?, 0x1234_5678);
+        assert_eq!(Grey::<f64>::from_u32(0x80)?.to_u32()?, 0x80);
+        assert_eq!(Srgb::<f64>::from_u32(0xAA12_3456)?.to_u32()?, 0x0012_3456);
+        Ok(())
+    }
+
+    #[test]
+    fn inspect_shows_space_name_and_hex() -> Result<()> {
+        let red = Srgb::<f64>::from_hex("#ff0000")?;
+
+        let lab = red.to_lab()?.inspect()?;
+        assert!(lab.starts_with("Lab {"));
+        assert!(lab.contains("hex: \"#FF0000\""));
+        assert_eq!(red.inspect()?, "Srgb { hex: \"#FF0000\", components: [1.0, 0.0, 0.0] }");
+        Ok(())
+    }
+
+    #[test]
+    fn from_byte_slice_checks_length() -> Result<()> {
+        assert!(matches!(
+            Srgb::<f64>::from_byte_slice(&[255, 0]),
+            Err(ChromaticError::ColourParsing(ColourParsingError::InvalidComponentCount {
+                expected: 3,
+                actual: 2
+            }))
+        ));
+
+        let colour = Srgb::<f64>::from_byte_slice(&[255, 128, 0])?;
+        assert_eq!(colour.to_bytes()?, [255, 128, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn default_bytes16_scale_unit_components() -> Result<()> {
+        let grey = Grey::<f64>::from_bytes16([32768])?;
+
+        assert!((grey.grey() - 32768.0 / 65535.0).abs() < 1e-12);
+        assert_eq!(grey.to_bytes16()?, [32768]);
+        Ok(())
+    }
 }
@@ -1,12 +1,70 @@
 //! Trait implemented by all colour types.
 
+use alloc::string::String;
+use core::cmp::Ordering;
 use num_traits::Float;
 
 use crate::{
-    error::Result,
-    spaces::{Grey, GreyAlpha, Hsl, HslAlpha, Hsv, HsvAlpha, Lab, LabAlpha, Rgb, RgbAlpha, Srgb, SrgbAlpha, Xyz, XyzAlpha},
+    config::JUST_NOTICEABLE_DIFFERENCE,
+    error::{Result, clamp_unit_component, safe_constant, validate_component_range},
+    spaces::{
+        AdobeRgb, Cmyk, DisplayP3, Grey, GreyAlpha, Hpluv, Hsl, HslAlpha, Hsluv, Hsv, HsvAlpha, Lab, LabAlpha, Lchuv, Luv,
+        Oklab, Rgb, RgbAlpha, Srgb, SrgbAlpha, XyY, Xyz, XyzAlpha,
+    },
+    temperature::Temperature,
+    traits::Colour,
 };
 
+/// Range of colours a colour space can represent, partially ordered by containment.
+///
+/// A gamut compares greater than another when it contains it. `AdobeRgb` and `DisplayP3` are incomparable,
+/// as each covers colours the other does not: Adobe RGB reaches further into the greens, and Display P3 into the reds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gamut {
+    /// Achromatic colours only.
+    Grey,
+    /// The sRGB gamut, shared by all RGB cube based spaces (`Rgb`, `Srgb`, `Hsl` and `Hsv`).
+    Srgb,
+    /// The Adobe RGB (1998) gamut.
+    AdobeRgb,
+    /// The Display P3 gamut.
+    DisplayP3,
+    /// Device-independent spaces covering the visible colours (`Lab`, `Luv`, `Lchuv`, `XyY` and `Xyz`).
+    Visible,
+    /// Sampled reflectance spectra, incomparable with every tristimulus gamut.
+    ///
+    /// Reducing a spectrum to three components discards the detail that separates metamers,
+    /// and rebuilding a spectrum from three components only picks one plausible candidate.
+    Spectral,
+}
+
+impl Gamut {
+    /// Position in the containment order, shared by gamuts that are incomparable, or `None` outside the order.
+    const fn rank(self) -> Option<u8> {
+        match self {
+            Self::Grey => Some(0),
+            Self::Srgb => Some(1),
+            Self::AdobeRgb | Self::DisplayP3 => Some(2),
+            Self::Visible => Some(3),
+            Self::Spectral => None,
+        }
+    }
+}
+
+impl PartialOrd for Gamut {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+
+        // Distinct gamuts of the same rank overlap without either containing the other
+        match self.rank()?.cmp(&other.rank()?) {
+            Ordering::Equal => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
 /// Types implementing this trait can be converted to various `Colour` `crate::spaces`.
 ///
 /// This trait provides a unified interface for converting between different colour spaces.
@@ -33,6 +91,163 @@ use crate::{
 /// Most errors are recoverable and indicate either invalid input values or limitations
 /// in the conversion process rather than programming errors.
 pub trait Convert<T: Float + Send + Sync> {
+    /// The range of colours this colour space can represent.
+    const GAMUT: Gamut;
+
+    /// Whether this colour space has an alpha channel.
+    const HAS_ALPHA: bool = false;
+
+    /// Check whether converting to colour space `D` preserves all information, up to floating-point rounding.
+    ///
+    /// Conversions are lossless when `D` covers this colour space's gamut and keeps any alpha channel.
+    /// For example, `Rgb` to `Hsl` is lossless, while any chromatic colour space to `Grey` is lossy.
+    ///
+    /// Lossless round trips are reversible only approximately, not bit for bit. `Rgb` to `Hsl` and back passes
+    /// through the sRGB transfer function, recovering each component to about 1e-12, or 1e-4 with `fast-gamma`.
+    #[must_use]
+    fn is_lossless_to<D: Convert<T>>() -> bool
+    where
+        Self: Sized,
+    {
+        D::GAMUT >= Self::GAMUT && (D::HAS_ALPHA || !Self::HAS_ALPHA)
+    }
+
+    /// Create a colour of this type from an `RgbAlpha` colour.
+    ///
+    /// Colour types without an alpha channel discard the alpha component.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The conversion from RGB into this colour space fails
+    /// - Component validation fails for the resulting colour
+    fn from_rgb_alpha(colour: &RgbAlpha<T>) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Create a colour of this type from a `Lab` colour.
+    ///
+    /// By default the colour is first mapped into the sRGB gamut by reducing its chroma
+    /// (see [`Lab::map_to_srgb_gamut`]) and then converted through `RgbAlpha`, so out-of-gamut colours keep
+    /// their lightness and hue rather than being clamped channel by channel. `Lab` converts without loss.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if gamut mapping or the conversion into this colour space fails.
+    fn from_lab(colour: &Lab<T>) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_rgb_alpha(&colour.map_to_srgb_gamut()?.to_rgb_alpha()?)
+    }
+
+    /// Convert a colour to the `AdobeRgb` colour space.
+    ///
+    /// Colours outside the Adobe RGB (1998) gamut are clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Intermediate XYZ conversion fails
+    /// - Gamma encoding fails
+    /// - Component validation fails for the resulting colour
+    fn to_adobe_rgb(&self) -> Result<AdobeRgb<T>> {
+        AdobeRgb::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `DisplayP3` colour space.
+    ///
+    /// Colours outside the Display P3 gamut are clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Intermediate XYZ conversion fails
+    /// - Gamma encoding fails
+    /// - Component validation fails for the resulting colour
+    fn to_display_p3(&self) -> Result<DisplayP3<T>> {
+        DisplayP3::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `Cmyk` colour space, via sRGB.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate sRGB conversion fails.
+    fn to_cmyk(&self) -> Result<Cmyk<T>> {
+        Cmyk::from_srgb(&self.to_srgb()?)
+    }
+
+    /// Convert a colour to the `Hsluv` colour space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate XYZ conversion fails.
+    fn to_hsluv(&self) -> Result<Hsluv<T>> {
+        Hsluv::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `Hpluv` colour space.
+    /// Colours more saturated than the pastel range covered by `HPLuv` have their saturation clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate XYZ conversion fails.
+    fn to_hpluv(&self) -> Result<Hpluv<T>> {
+        Hpluv::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `Luv` (CIE L*u*v*) colour space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate XYZ conversion fails, or if u* or v* fall outside [-200, 200].
+    fn to_luv(&self) -> Result<Luv<T>> {
+        Luv::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `Lchuv` (`LCh(uv)`) colour space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate `Luv` conversion fails.
+    fn to_lchuv(&self) -> Result<Lchuv<T>> {
+        Lchuv::from_luv(&self.to_luv()?)
+    }
+
+    /// Convert a colour to the `XyY` (CIE xyY) colour space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate XYZ conversion fails.
+    fn to_xyy(&self) -> Result<XyY<T>> {
+        XyY::from_xyz(&self.to_xyz()?)
+    }
+
+    /// Convert a colour to the `Oklab` colour space.
+    /// Colours outside the sRGB gamut are clamped to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate RGB conversion fails.
+    fn to_oklab(&self) -> Result<Oklab<T>> {
+        Oklab::from_rgb(&self.to_rgb()?)
+    }
+
+    /// Convert a colour to greyscale by its relative luminance, the XYZ `Y` component.
+    ///
+    /// Unlike [`Convert::to_grey`], whose meaning differs between colour spaces (`Rgb` averages its channels,
+    /// while `Lab` uses L* and `Xyz` uses `Y`), this routes every colour space through XYZ, so equivalent
+    /// colours give the same grey regardless of their representation.
+    /// Luminance above 1 is clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if intermediate XYZ conversion fails.
+    fn to_grey_perceptual(&self) -> Result<Grey<T>> {
+        Grey::new(clamp_unit_component(self.to_xyz()?.y()))
+    }
+
     /// Convert a colour to the `Grey` colour space.
     ///
     /// # Errors
@@ -179,4 +394,244 @@ pub trait Convert<T: Float + Send + Sync> {
     /// - Alpha component validation fails (should be [0, 1])
     /// - Type conversion operations fail
     fn to_xyz_alpha(&self) -> Result<XyzAlpha<T>>;
+
+    /// Format the colour as a CSS colour string.
+    ///
+    /// By default this is a lowercase `#rrggbb` hex string of the sRGB colour, with any alpha discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conversion to sRGB fails.
+    fn to_css_string(&self) -> Result<String> {
+        Ok(self.to_srgb()?.to_hex()?.to_lowercase())
+    }
+
+    /// Classify a colour as warm, cool or neutral by its CIE L*a*b* hue angle.
+    ///
+    /// Colours with a chroma below `Temperature::DEFAULT_CHROMA_THRESHOLD` are neutral.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The underlying `to_lab()` conversion fails
+    /// - Type conversion of the threshold fails
+    fn temperature_class(&self) -> Result<Temperature> {
+        self.temperature_class_with_threshold(safe_constant(Temperature::DEFAULT_CHROMA_THRESHOLD)?)
+    }
+
+    /// Classify a colour as warm, cool or neutral using a custom chroma threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `chroma_threshold` - Chroma below which the colour is considered neutral
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The underlying `to_lab()` conversion fails
+    /// - Type conversion of the hue bounds fails
+    fn temperature_class_with_threshold(&self, chroma_threshold: T) -> Result<Temperature> {
+        Temperature::from_lab(&self.to_lab()?, chroma_threshold)
+    }
+
+    /// Check whether two colours are too similar for most observers to tell apart.
+    ///
+    /// Uses a CIEDE2000 just-noticeable difference of `JUST_NOTICEABLE_DIFFERENCE`.
+    /// Alpha components are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The colour to compare against
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conversion to Lab or the colour difference calculation fails.
+    fn is_indistinguishable_from(&self, other: &Self) -> Result<bool>
+    where
+        Self: Sized,
+    {
+        self.is_perceptually_equal(other, safe_constant(JUST_NOTICEABLE_DIFFERENCE)?)
+    }
+
+    /// Check whether the CIEDE2000 difference between two colours is within a just-noticeable difference.
+    /// Alpha components are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The colour to compare against
+    /// * `jnd` - The largest difference considered imperceptible, must be non-negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `jnd` is negative
+    /// - The conversion to Lab or the colour difference calculation fails
+    fn is_perceptually_equal(&self, other: &Self, jnd: T) -> Result<bool>
+    where
+        Self: Sized,
+    {
+        validate_component_range(jnd, "jnd", T::zero(), T::infinity())?;
+        Ok(self.to_lab()?.delta_e2000(&other.to_lab()?)? <= jnd)
+    }
+
+    /// Limit the CIE `LCh` chroma of a colour, preserving its hue and lightness.
+    ///
+    /// Colours with a chroma at or below `max_chroma` are returned unchanged, without a round trip through RGB.
+    /// The alpha component is preserved for colour spaces with transparency.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_chroma` - The maximum chroma, must be non-negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `max_chroma` is negative
+    /// - The conversion to or from Lab fails
+    fn clamp_chroma(&self, max_chroma: T) -> Result<Self>
+    where
+        Self: Sized + Clone,
+    {
+        validate_component_range(max_chroma, "max_chroma", T::zero(), T::infinity())?;
+
+        let lab = self.to_lab()?;
+        let chroma = lab.a_star().hypot(lab.b_star());
+        if chroma <= max_chroma {
+            return Ok(self.clone());
+        }
+        let scale = max_chroma / chroma;
+
+        let clamped = Lab::new(lab.lightness(), lab.a_star() * scale, lab.b_star() * scale)?.to_rgb()?;
+        let alpha = self.to_rgb_alpha()?.alpha();
+        Self::from_rgb_alpha(&RgbAlpha::new(clamped.red(), clamped.green(), clamped.blue(), alpha)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::GammaPrecision;
+    #[cfg(feature = "spectral")]
+    use crate::spaces::Spectral;
+
+    #[test]
+    fn clamp_chroma_reduces_neon_chroma_and_keeps_hue() -> Result<()> {
+        let neon = Srgb::<f64>::from_hex("#39ff14")?;
+        let before = neon.to_lab()?;
+
+        let after = neon.clamp_chroma(30.0)?.to_lab()?;
+        assert!(before.chroma() > 100.0);
+        assert!((after.chroma() - 30.0).abs() < 0.5);
+        assert!((after.lightness() - before.lightness()).abs() < 0.5);
+
+        let hue = |lab: &Lab<f64>| lab.b_star().atan2(lab.a_star()).to_degrees();
+        assert!((hue(&after) - hue(&before)).abs() < 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn clamp_chroma_returns_muted_colours_unchanged() -> Result<()> {
+        let muted = Rgb::<f64>::new(0.4, 0.42, 0.41)?;
+
+        let clamped = muted.clamp_chroma(30.0)?;
+        assert_eq!(clamped.components().map(f64::to_bits), muted.components().map(f64::to_bits));
+        Ok(())
+    }
+
+    #[test]
+    fn is_lossless_to_reports_gamut_and_alpha_coverage() {
+        assert!(Rgb::<f64>::is_lossless_to::<Hsl<f64>>());
+        assert!(Hsl::<f64>::is_lossless_to::<Rgb<f64>>());
+        assert!(Rgb::<f64>::is_lossless_to::<Lab<f64>>());
+        assert!(Grey::<f64>::is_lossless_to::<Rgb<f64>>());
+        assert!(!Rgb::<f64>::is_lossless_to::<Grey<f64>>());
+        assert!(!RgbAlpha::<f64>::is_lossless_to::<Rgb<f64>>());
+        assert!(!AdobeRgb::<f64>::is_lossless_to::<Srgb<f64>>());
+        #[cfg(feature = "spectral")]
+        {
+            assert!(!Rgb::<f64>::is_lossless_to::<Spectral<f64>>());
+            assert!(!Spectral::<f64>::is_lossless_to::<Xyz<f64>>());
+            assert!(Spectral::<f64>::is_lossless_to::<Spectral<f64>>());
+        }
+    }
+
+    #[test]
+    fn rgb_round_trips_through_hsl() -> Result<()> {
+        // HSL is defined over gamma-encoded sRGB, whose approximate curve is only accurate to about 1e-4
+        let tolerance = if GammaPrecision::DEFAULT == GammaPrecision::Exact {
+            1e-12
+        } else {
+            2e-4
+        };
+        for [red, green, blue] in [[0.2, 0.4, 0.6], [1.0, 0.0, 0.5], [0.9, 0.9, 0.1], [0.0, 0.0, 0.0]] {
+            let colour = Rgb::<f64>::new(red, green, blue)?;
+
+            let round_trip = colour.to_hsl()?.to_rgb()?;
+            for (lhs, rhs) in round_trip.components().iter().zip(colour.components()) {
+                assert!((lhs - rhs).abs() < tolerance);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn perceptual_equality_uses_jnd_threshold() -> Result<()> {
+        let base = Lab::<f64>::new(50.0, 10.0, 10.0)?;
+        let near = Lab::new(50.5, 10.0, 10.0)?;
+        let far = Lab::new(55.0, 10.0, 10.0)?;
+
+        assert!((base.delta_e2000(&near)? - 0.5).abs() < 0.01);
+        assert!((base.delta_e2000(&far)? - 5.0).abs() < 0.1);
+        assert!(base.is_perceptually_equal(&near, 1.0)?);
+        assert!(!base.is_perceptually_equal(&far, 1.0)?);
+        assert!(base.is_indistinguishable_from(&near)?);
+        assert!(!base.is_indistinguishable_from(&far)?);
+        assert!(base.is_perceptually_equal(&near, -1.0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn css_strings_use_canonical_forms() -> Result<()> {
+        assert_eq!(Srgb::<f64>::new(1.0, 0.0, 0.0)?.to_css_string()?, "#ff0000");
+        assert_eq!(Lab::<f64>::new(100.0, 0.0, 0.0)?.to_css_string()?, "#ffffff");
+        assert_eq!(Hsl::<f64>::new(120.4, 0.504, 0.496)?.to_css_string()?, "hsl(120, 50%, 50%)");
+        assert_eq!(
+            SrgbAlpha::<f64>::new(1.0, 0.0, 0.0, 0.5)?.to_css_string()?,
+            "rgba(255, 0, 0, 0.5)"
+        );
+        assert_eq!(
+            RgbAlpha::<f64>::new(0.0, 0.0, 1.0, 0.25)?.to_css_string()?,
+            "rgba(0, 0, 255, 0.25)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn perceptual_grey_agrees_across_representations() -> Result<()> {
+        let rgb = Rgb::<f64>::new(0.2, 0.5, 0.7)?;
+        let srgb = rgb.to_srgb()?;
+        let lab = rgb.to_lab()?;
+
+        let expected = rgb.to_grey_perceptual()?.grey();
+        assert!((expected - (0.2126 * 0.2 + 0.7152 * 0.5 + 0.0722 * 0.7)).abs() < 1e-4);
+        for grey in [srgb.to_grey_perceptual()?, lab.to_grey_perceptual()?] {
+            assert!((grey.grey() - expected).abs() < 1e-4);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hsl_css_strings_round_trip_to_browser_hex() -> Result<()> {
+        for (hex, css) in [
+            ("#800000", "hsl(0, 100%, 25%)"),
+            ("#FF8000", "hsl(30, 100%, 50%)"),
+            ("#336699", "hsl(210, 50%, 40%)"),
+            ("#808080", "hsl(0, 0%, 50%)"),
+        ] {
+            let formatted = Srgb::<f64>::from_hex(hex)?.to_hsl()?.to_css_string()?;
+            assert_eq!(formatted, css);
+            assert_eq!(Hsl::<f64>::from_css(&formatted)?.to_hex()?, hex);
+        }
+        Ok(())
+    }
 }
@@ -1,5 +1,9 @@
+mod alpha;
+mod approx_eq;
 mod colour;
 mod convert;
 
+pub use alpha::Alpha;
+pub use approx_eq::ApproxEq;
 pub use colour::Colour;
-pub use convert::Convert;
+pub use convert::{Convert, Gamut};
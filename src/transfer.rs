@@ -0,0 +1,198 @@
+//! ## Transfer Function Module
+//!
+//! This module provides the transfer functions used to encode linear light into signal values,
+//! including the HDR curves SMPTE ST 2084 (PQ) and ARIB STD-B67 (HLG).
+
+use num_traits::Float;
+
+use crate::{
+    error::{Result, safe_constant, validate_gamma, validate_unit_component},
+    spaces::Srgb,
+};
+
+/// PQ exponent `m1`.
+const PQ_M1: f64 = 2610.0 / 16384.0;
+/// PQ exponent `m2`.
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+/// PQ constant `c1`.
+const PQ_C1: f64 = 3424.0 / 4096.0;
+/// PQ constant `c2`.
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+/// PQ constant `c3`.
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// HLG constant `a`.
+const HLG_A: f64 = 0.178_832_77;
+/// HLG constant `b`.
+const HLG_B: f64 = 0.284_668_92;
+/// HLG constant `c`.
+const HLG_C: f64 = 0.559_910_73;
+
+/// Transfer functions mapping linear light to encoded signal values.
+///
+/// All functions operate on components in the range [0, 1].
+/// For PQ, a linear value of 1 corresponds to an absolute luminance of 10000 cd/m².
+#[derive(Debug, Clone, Copy)]
+pub enum TransferFunction<T: Float + Send + Sync> {
+    /// Identity: the signal is linear light.
+    Linear,
+    /// The piecewise sRGB (IEC 61966-2-1) curve.
+    Srgb,
+    /// A pure power law with the given gamma exponent.
+    Gamma(T),
+    /// SMPTE ST 2084 perceptual quantizer.
+    Pq,
+    /// ITU-R BT.2100 hybrid log-gamma (OETF).
+    Hlg,
+}
+
+impl<T: Float + Send + Sync> TransferFunction<T> {
+    /// Encode a linear component into a signal value.
+    ///
+    /// # Arguments
+    ///
+    /// * `linear` - Linear component value in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1],
+    /// if the gamma exponent is not positive and finite, or if constant conversion fails.
+    pub fn encode(self, linear: T) -> Result<T> {
+        validate_unit_component(linear, "linear")?;
+
+        match self {
+            Self::Linear => Ok(linear),
+            Self::Srgb => Srgb::gamma_encode(linear),
+            Self::Gamma(gamma) => {
+                validate_gamma(gamma)?;
+                Ok(linear.powf(gamma.recip()))
+            }
+            Self::Pq => {
+                let y_m1 = linear.powf(safe_constant(PQ_M1)?);
+                let numerator = safe_constant::<f64, T>(PQ_C1)? + safe_constant::<f64, T>(PQ_C2)? * y_m1;
+                let denominator = T::one() + safe_constant::<f64, T>(PQ_C3)? * y_m1;
+                Ok((numerator / denominator).powf(safe_constant(PQ_M2)?))
+            }
+            Self::Hlg => {
+                let twelfth = safe_constant::<f64, T>(1.0 / 12.0)?;
+                if linear <= twelfth {
+                    Ok((safe_constant::<f64, T>(3.0)? * linear).sqrt())
+                } else {
+                    let scaled = safe_constant::<f64, T>(12.0)? * linear - safe_constant(HLG_B)?;
+                    Ok(safe_constant::<f64, T>(HLG_A)? * scaled.ln() + safe_constant(HLG_C)?)
+                }
+            }
+        }
+    }
+
+    /// Decode a signal value into a linear component.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoded` - Encoded signal value in range [0, 1]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is outside the range [0, 1],
+    /// if the gamma exponent is not positive and finite, or if constant conversion fails.
+    pub fn decode(self, encoded: T) -> Result<T> {
+        validate_unit_component(encoded, "encoded")?;
+
+        match self {
+            Self::Linear => Ok(encoded),
+            Self::Srgb => Srgb::gamma_decode(encoded),
+            Self::Gamma(gamma) => {
+                validate_gamma(gamma)?;
+                Ok(encoded.powf(gamma))
+            }
+            Self::Pq => {
+                let e_m2 = encoded.powf(safe_constant::<f64, T>(PQ_M2)?.recip());
+                let numerator = (e_m2 - safe_constant(PQ_C1)?).max(T::zero());
+                let denominator = safe_constant::<f64, T>(PQ_C2)? - safe_constant::<f64, T>(PQ_C3)? * e_m2;
+                Ok((numerator / denominator).powf(safe_constant::<f64, T>(PQ_M1)?.recip()))
+            }
+            Self::Hlg => {
+                if encoded <= safe_constant(0.5)? {
+                    Ok(encoded * encoded / safe_constant(3.0)?)
+                } else {
+                    let exponent = (encoded - safe_constant(HLG_C)?) / safe_constant(HLG_A)?;
+                    Ok((exponent.exp() + safe_constant(HLG_B)?) / safe_constant(12.0)?)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::{GammaPrecision, Rgb};
+
+    #[test]
+    fn pq_maps_100_nits_to_documented_code_value() -> Result<()> {
+        let encoded = TransferFunction::<f64>::Pq.encode(100.0 / 10000.0)?;
+
+        assert!((encoded - 0.508_078).abs() < 1e-5);
+        assert!((TransferFunction::<f64>::Pq.encode(1.0)? - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn hlg_reference_points() -> Result<()> {
+        assert!((TransferFunction::<f64>::Hlg.encode(1.0 / 12.0)? - 0.5).abs() < 1e-12);
+        assert!((TransferFunction::<f64>::Hlg.encode(1.0)? - 1.0).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn srgb_matches_default_gamma_encoding() -> Result<()> {
+        let encoded = TransferFunction::<f64>::Srgb.encode(0.2)?;
+
+        assert!((encoded - Srgb::gamma_encode(0.2)?).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn encode_decode_round_trips() -> Result<()> {
+        let functions = [
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Pq,
+            TransferFunction::Hlg,
+        ];
+        for function in functions {
+            // The approximate sRGB curve is only accurate to about 1e-4 in each direction
+            let tolerance = match function {
+                TransferFunction::Srgb if GammaPrecision::DEFAULT == GammaPrecision::Fast => 2e-4,
+                _ => 1e-9,
+            };
+            for linear in [0.0, 0.001, 0.01, 0.2, 0.5, 0.9, 1.0] {
+                let round_trip = function.decode(function.encode(linear)?)?;
+                assert!(
+                    (round_trip - linear).abs() < tolerance,
+                    "{function:?} at {linear}: {round_trip}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_out_of_range_components() {
+        assert!(TransferFunction::<f64>::Pq.encode(1.5).is_err());
+        assert!(TransferFunction::<f64>::Gamma(0.0).encode(0.5).is_err());
+    }
+
+    #[test]
+    fn rgb_round_trips_through_an_encoding() -> Result<()> {
+        let colour = Rgb::<f64>::from_encoded(0.5, 0.25, 0.75, TransferFunction::Pq)?;
+
+        assert!((colour.red() - TransferFunction::Pq.decode(0.5)?).abs() < 1e-12);
+        let encoded = colour.to_encoded(TransferFunction::Pq)?;
+        for (lhs, rhs) in encoded.iter().zip([0.5, 0.25, 0.75]) {
+            assert!((lhs - rhs).abs() < 1e-9);
+        }
+        Ok(())
+    }
+}